@@ -0,0 +1,94 @@
+// OCR throughput of the `api` module. Run with
+// `cargo bench --features api`; skips, like the tests, without tessdata.
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use common::fixture;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use leptonica_sys::{pixConvertTo8, pixGetPixel};
+use std::thread;
+use tesseract_sys::api::{Pix, PixelFormat, TessBaseApi, TessConfig, TessPool};
+
+const IMAGE: &str = "img.png";
+
+/// 8 bit grey pixels of a fixture, one byte per pixel.
+fn grey_bytes(path: &str) -> (Vec<u8>, i32, i32) {
+    let image = fixture(path);
+    let grey = unsafe { Pix::from_raw(pixConvertTo8(image.as_ptr(), 0)) }.unwrap();
+    let mut bytes = Vec::with_capacity((grey.width() * grey.height()) as usize);
+    for y in 0..grey.height() {
+        for x in 0..grey.width() {
+            let mut value = 0;
+            unsafe { pixGetPixel(grey.as_ptr(), x, y, &mut value) };
+            bytes.push(value as u8);
+        }
+    }
+    (bytes, grey.width(), grey.height())
+}
+
+/// A new API per image: language data is loaded every time.
+fn cold_init(c: &mut Criterion) {
+    require_tessdata!("eng");
+    let image = fixture(IMAGE);
+    let mut group = c.benchmark_group("cold_init");
+    group.sample_size(10);
+    group.bench_function("init_and_recognize", |b| {
+        b.iter(|| {
+            let mut api = TessBaseApi::new().unwrap();
+            api.init(None, "eng").unwrap();
+            api.set_image_pix(&image);
+            api.get_utf8_text().unwrap()
+        })
+    });
+    group.finish();
+}
+
+/// One API initialised up front and cleared between images.
+fn warm(c: &mut Criterion) {
+    require_tessdata!("eng");
+    let image = fixture(IMAGE);
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let mut group = c.benchmark_group("warm");
+    group.sample_size(20);
+    group.bench_function("recognize", |b| {
+        b.iter(|| {
+            api.set_image_pix(&image);
+            let text = api.get_utf8_text().unwrap();
+            api.clear();
+            text
+        })
+    });
+    group.finish();
+}
+
+/// A batch of images spread over one thread per core through a `TessPool`,
+/// whose APIs are created before measuring starts.
+fn pooled(c: &mut Criterion) {
+    require_tessdata!("eng");
+    let (bytes, width, height) = grey_bytes(IMAGE);
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let batch = 2 * threads;
+    let pool = TessPool::new(TessConfig::new(), None, "eng", threads);
+    let ocr_batch = || {
+        thread::scope(|s| {
+            for _ in 0..batch {
+                s.spawn(|| {
+                    pool.ocr_image(&bytes, width, height, PixelFormat::Grayscale)
+                        .unwrap()
+                });
+            }
+        })
+    };
+    ocr_batch();
+
+    let mut group = c.benchmark_group("pooled");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(batch as u64));
+    group.bench_function(format!("{}_threads", threads), |b| b.iter(&ocr_batch));
+    group.finish();
+}
+
+criterion_group!(benches, cold_init, warm, pooled);
+criterion_main!(benches);
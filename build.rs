@@ -2,30 +2,207 @@ extern crate bindgen;
 
 use std::env;
 use std::fs;
-use std::fs::File;
-use std::path::PathBuf;
-use std::process::Command;
-use std::io::Write;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
 #[cfg(windows)]
 use vcpkg;
 
+#[path = "build/arch.rs"]
+mod arch;
+#[path = "build/bundled.rs"]
+mod bundled;
+#[path = "build/link.rs"]
+mod link;
+#[path = "build/manifest.rs"]
+mod manifest;
+#[path = "build/paths.rs"]
+mod paths;
+#[path = "build/probe.rs"]
+mod probe;
+// The macOS public types are pre-generated.
+#[cfg_attr(target_os = "macos", allow(dead_code))]
+#[path = "build/public_types.rs"]
+mod public_types;
+#[path = "build/sanitizer.rs"]
+mod sanitizer;
+#[path = "build/source.rs"]
+mod source;
+#[path = "build/symbols.rs"]
+mod symbols;
+#[path = "build/version.rs"]
+mod version;
+
+use bundled::{find_bundled_library, BundledLibrary};
+use manifest::{parse_manifest, select_entry};
+use source::LibrarySource;
+
 const TESSERACT_VERSION: &str = "5.3.4";
-const LIBS_PATH: &str = "resources/libs/";
+// Version the checked-in bindings under prebuilt/ were generated from.
+const PREBUILT_VERSION: &str = "5.3.4";
+const LIBS_PATH: &str = "resources/libs";
+
+// Names of the files written to OUT_DIR. When TESSERACT_BINDINGS_PREFIX is
+// set, it is prepended to both names; when unset, the names are unchanged.
+const CAPI_BINDINGS_FILE: &str = "capi_bindings.rs";
+const PUBLIC_TYPES_BINDINGS_FILE: &str = "public_types_bindings.rs";
+
+fn bindings_prefix() -> String {
+    println!("cargo:rerun-if-env-changed=TESSERACT_BINDINGS_PREFIX");
+    let prefix = env::var("TESSERACT_BINDINGS_PREFIX").unwrap_or_default();
+    // lib.rs reads the prefix back to build its include! paths.
+    println!("cargo:rustc-env=TESSERACT_BINDINGS_PREFIX={}", prefix);
+    prefix
+}
+
+// For inspecting the generated bindings during development:
+// TESSERACT_DUMP_BINDINGS names a directory that gets a copy of each file
+// written to OUT_DIR. A relative path is relative to this crate's directory.
+fn bindings_dump_dir() -> Option<PathBuf> {
+    println!("cargo:rerun-if-env-changed=TESSERACT_DUMP_BINDINGS");
+    let dir = env::var_os("TESSERACT_DUMP_BINDINGS").filter(|dir| !dir.is_empty())?;
+    let dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join(dir);
+    println!(
+        "cargo:warning=Copying the generated bindings to {}",
+        dir.display()
+    );
+    Some(dir)
+}
+
+// TESSERACT_DUMP_SIGNATURES names a file that gets the name, number of
+// parameters and signature of every C API function bound, one per line, for
+// looking a function up without reading the bindings. A relative path is
+// relative to this crate's directory.
+fn signatures_dump_path() -> Option<PathBuf> {
+    println!("cargo:rerun-if-env-changed=TESSERACT_DUMP_SIGNATURES");
+    let path = env::var_os("TESSERACT_DUMP_SIGNATURES").filter(|path| !path.is_empty())?;
+    let path = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join(path);
+    println!(
+        "cargo:warning=Writing the signatures of the bound functions to {}",
+        path.display()
+    );
+    Some(path)
+}
+
+// Lets deployments that only ship the versioned library link it by file name,
+// e.g. TESSERACT_LINK_LIB_NAME=libtesseract.so.5.
+fn link_tesseract(default: &str) {
+    println!("cargo:rerun-if-env-changed=TESSERACT_LINK_LIB_NAME");
+    let custom = env::var("TESSERACT_LINK_LIB_NAME").ok();
+    let name = link::link_lib_name(custom.as_deref(), default);
+    println!("{}", link::link_lib(&name));
+}
+
+// Version of the tesseract being linked, when the way it was found tells.
+static LINKED_VERSION: OnceLock<String> = OnceLock::new();
+
+// With the windows-delay-load feature, tesseract's DLL is only loaded when
+// the first tesseract function is called. TESSERACT_DELAYLOAD_DLL names the
+// DLL if it isn't vcpkg's tesseract53.dll.
+fn emit_delay_load() {
+    if env::var_os("CARGO_FEATURE_WINDOWS_DELAY_LOAD").is_none() {
+        return;
+    }
+    println!("cargo:rerun-if-env-changed=TESSERACT_DELAYLOAD_DLL");
+    let dll = env::var("TESSERACT_DELAYLOAD_DLL").ok();
+    let dll = link::link_lib_name(dll.as_deref(), link::DEFAULT_DELAY_LOAD_DLL);
+    let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
+    let directives = link::delay_load(&target_env, &dll);
+    if directives.is_empty() {
+        println!("cargo:warning=windows-delay-load only has an effect on MSVC targets");
+    }
+    for directive in directives {
+        println!("{}", directive);
+    }
+}
+
+// Wrappers of functions missing from older versions are left out, so calling
+// them is a compile error rather than an undefined reference at link time.
+fn emit_version_cfgs(version: &str) {
+    let _ = LINKED_VERSION.set(version.to_string());
+    for cfg in version::version_cfgs(version) {
+        println!("cargo:rustc-cfg={}", cfg);
+    }
+}
+
+// vcpkg, TESSERACT_INCLUDE_PATHS on Windows and systems without pkg-config
+// don't report the version, so it is taken from TESSERACT_VERSION or, failing
+// that, from tesseract/version.h in the include paths.
+fn unreported_version(include_paths: &[PathBuf]) -> Option<String> {
+    println!("cargo:rerun-if-env-changed=TESSERACT_VERSION");
+    if let Some(version) = env::var("TESSERACT_VERSION")
+        .ok()
+        .filter(|version| !version.trim().is_empty())
+    {
+        return Some(version);
+    }
+    include_paths.iter().find_map(|dir| {
+        let header = dir.join("tesseract").join("version.h");
+        println!("cargo:rerun-if-changed={}", header.display());
+        version::header_version(&fs::read_to_string(header).ok()?)
+    })
+}
+
+// The system library is tried first and the bundled one is the fallback,
+// unless TESSERACT_LINK_PREFERENCE=bundled reverses the order. Builds run
+// under a sanitizer (TESSERACT_SANITIZER=address, ...) can't use the bundled
+// library, so they only try the system one.
+fn find_tesseract_lib() -> Vec<PathBuf> {
+    println!("cargo:rerun-if-env-changed=TESSERACT_SANITIZER");
+    let value = env::var("TESSERACT_SANITIZER").ok();
+    let sanitizer = sanitizer::sanitizer(value.as_deref());
+    if let Some(name) = sanitizer {
+        if !sanitizer::KNOWN_SANITIZERS.contains(&name) {
+            println!(
+                "cargo:warning=Unknown TESSERACT_SANITIZER `{}`; linking the system tesseract anyway",
+                name
+            );
+        }
+    }
+    println!("cargo:rerun-if-env-changed=TESSERACT_LINK_PREFERENCE");
+    let preference = env::var("TESSERACT_LINK_PREFERENCE").ok();
+    let order =
+        source::link_order(preference.as_deref(), sanitizer).unwrap_or_else(|e| panic!("{}", e));
+    let found = source::first_found(&order, |source| match source {
+        LibrarySource::Bundled => find_bundled_tesseract_lib(),
+        LibrarySource::System => find_tesseract_system_lib(),
+    });
+    match found {
+        Ok((source, include_paths, failures)) => {
+            for (failed, e) in failures {
+                println!("cargo:warning=No {} tesseract library: {}", failed, e);
+            }
+            println!("cargo:warning=Using the {} tesseract library", source);
+            if LINKED_VERSION.get().is_none() {
+                if let Some(version) = unreported_version(&include_paths) {
+                    emit_version_cfgs(&version);
+                }
+            }
+            include_paths
+        }
+        Err(failures) => {
+            let reasons: Vec<String> = failures
+                .iter()
+                .map(|(source, e)| format!("{}: {}", source, e))
+                .collect();
+            panic!("No tesseract library found.\n{}", reasons.join("\n"))
+        }
+    }
+}
 
 #[cfg(windows)]
-fn find_tesseract_system_lib() -> Vec<String> {
+fn find_tesseract_system_lib() -> Result<Vec<PathBuf>, String> {
     println!("cargo:rerun-if-env-changed=TESSERACT_INCLUDE_PATHS");
     println!("cargo:rerun-if-env-changed=TESSERACT_LINK_PATHS");
     println!("cargo:rerun-if-env-changed=TESSERACT_LINK_LIBS");
 
-    let vcpkg = || {
-        let lib = vcpkg::Config::new().find_package("tesseract").unwrap();
+    let vcpkg = || -> Result<Vec<PathBuf>, String> {
+        let lib = vcpkg::Config::new()
+            .find_package("tesseract")
+            .map_err(|e| e.to_string())?;
 
-        vec![lib
-            .include_paths
-            .iter()
-            .map(|x| x.to_string_lossy())
-            .collect::<String>()]
+        Ok(lib.include_paths)
     };
 
     let include_paths = env::var("TESSERACT_INCLUDE_PATHS").ok();
@@ -38,14 +215,14 @@ fn find_tesseract_system_lib() -> Vec<String> {
         (include_paths, link_paths, link_libs)
     {
         for link_path in link_paths {
-            println!("cargo:rustc-link-search={}", link_path)
+            println!("{}", paths::link_search(link_path.as_ref()))
         }
 
         for link_lib in link_libs {
             println!("cargo:rustc-link-lib={}", link_lib)
         }
 
-        include_paths.map(|x| x.to_string()).collect::<Vec<_>>()
+        Ok(include_paths.map(PathBuf::from).collect::<Vec<_>>())
     } else {
         vcpkg()
     }
@@ -56,67 +233,172 @@ fn find_tesseract_system_lib() -> Vec<String> {
 // if you change install path(--prefix) to `configure` script.
 // set `export PKG_CONFIG_PATH=/path-to-lib/pkgconfig` before.
 #[cfg(any(target_os = "macos", target_os = "linux", target_os = "freebsd"))]
-fn find_tesseract_system_lib() -> Vec<String> {
-    let pk = pkg_config::Config::new()
-        .atleast_version("4.1")
-        .probe("tesseract")
-        .unwrap();
+fn find_tesseract_system_lib() -> Result<Vec<PathBuf>, String> {
+    if cfg!(target_os = "macos") {
+        add_homebrew_pkg_config_path();
+    }
+    println!("cargo:rerun-if-env-changed=TESSERACT_PROBE_RETRIES");
+    let retries = probe::probe_retries(env::var("TESSERACT_PROBE_RETRIES").ok().as_deref());
+    // A custom build may be named differently, e.g. tesseract53 or the debug
+    // build's tesseractd. TESSERACT_LINK_LIBS works as on Windows, replacing
+    // the libraries pkg-config would link.
+    println!("cargo:rerun-if-env-changed=TESSERACT_LINK_LIBS");
+    let link_libs = env::var("TESSERACT_LINK_LIBS").ok();
+    let link_libs = link::link_libs(link_libs.as_deref());
+    // Patched builds may report a version pkg-config can't compare.
+    println!("cargo:rerun-if-env-changed=TESSERACT_MIN_VERSION");
+    let min_version = env::var("TESSERACT_MIN_VERSION").ok();
+    let min_version = probe::min_version(min_version.as_deref());
+    let pk = probe::retry(
+        retries,
+        Duration::from_millis(500),
+        is_transient_probe_error,
+        || {
+            // Shared libraries only, even with PKG_CONFIG_ALL_STATIC: a
+            // sanitizer runtime has to interpose the C++ runtime tesseract
+            // links, which a static libstdc++ would hide.
+            let mut config = pkg_config::Config::new();
+            if let Some(version) = min_version {
+                config.atleast_version(version);
+            }
+            config
+                .statik(false)
+                .cargo_metadata(link_libs.is_none())
+                .probe("tesseract")
+        },
+    )
+    .map_err(|e| e.to_string())?;
+    emit_version_cfgs(&pk.version);
     // Tell cargo to tell rustc to link the system proj shared library.
-    println!("cargo:rustc-link-search=native={:?}", pk.link_paths[0]);
-    println!("cargo:rustc-link-lib=tesseract");
+    println!("{}", paths::link_search(&pk.link_paths[0]));
+    match link_libs {
+        Some(libs) => libs.iter().for_each(|lib| println!("{}", lib)),
+        None => link_tesseract("tesseract"),
+    }
 
-    let mut include_paths = pk.include_paths.clone();
-    include_paths
-        .iter_mut()
-        .map(|x| {
+    Ok(pk
+        .include_paths
+        .into_iter()
+        .map(|mut x| {
             if !x.ends_with("include") {
                 x.pop();
             }
             x
         })
-        .map(|x| x.to_string_lossy())
-        .map(|x| x.to_string())
-        .collect::<Vec<String>>()
+        .collect::<Vec<PathBuf>>())
 }
 
-fn reveal_location() -> std::io::Result<()> {
-    // Execute `pwd` command
-    let output = Command::new("pwd")
-        .output()
-        .expect("Failed to execute command");
-
-    // Convert the output to a String
-    let path = String::from_utf8_lossy(&output.stdout).trim().to_string();
-
-    // Get the home directory path
-    let home_dir = env::var("HOME").expect("HOME not set");
-
-    // Create a file path in the home directory
-    let file_path = format!("{}/location_revealed.txt", home_dir);
+// A pkg-config not installed by Homebrew doesn't search Homebrew's prefix,
+// which on Apple Silicon isn't /usr/local, and none searches the keg, so
+// both are added to the search path, after an explicit PKG_CONFIG_PATH.
+// pkg-config inherits the build script's environment.
+#[cfg(any(target_os = "macos", target_os = "linux", target_os = "freebsd"))]
+fn add_homebrew_pkg_config_path() {
+    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let current = env::var_os("PKG_CONFIG_PATH");
+    let homebrew = probe::homebrew_pkg_config_dirs(&target_arch);
+    if let Some(path) = probe::augmented_pkg_config_path(current.as_deref(), &homebrew) {
+        env::set_var("PKG_CONFIG_PATH", path);
+    }
+}
 
-    // Open a file in write mode
-    let mut file = File::create(file_path)?;
+// Failing to run pkg-config at all, or it failing for a reason other than the
+// package being missing, may be a concurrent package database update.
+#[cfg(any(target_os = "macos", target_os = "linux", target_os = "freebsd"))]
+fn is_transient_probe_error(err: &pkg_config::Error) -> bool {
+    match err {
+        pkg_config::Error::Command { .. } => true,
+        pkg_config::Error::Failure { output, .. } => {
+            !probe::is_not_found_message(&String::from_utf8_lossy(&output.stderr))
+        }
+        _ => false,
+    }
+}
 
-    // Write the command output to the file
-    writeln!(file, "{}", path)?;
+/// Directory (holding lib/ and include/), library name and version of the
+/// bundled tesseract for the target. Taken from the manifest when there is
+/// one, otherwise from TESSERACT_VERSION.
+fn bundled_tesseract_location() -> Result<(PathBuf, String, String), String> {
+    let tesseract_libs_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap())
+        .join(LIBS_PATH)
+        .join("tesseract");
+    let manifest_path = tesseract_libs_dir.join("manifest.toml");
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+    let manifest = match fs::read_to_string(&manifest_path) {
+        Ok(manifest) => manifest,
+        Err(_) => {
+            return Ok((
+                tesseract_libs_dir.join(TESSERACT_VERSION),
+                "tesseract".to_string(),
+                TESSERACT_VERSION.to_string(),
+            ))
+        }
+    };
+    let entries = parse_manifest(&manifest)
+        .unwrap_or_else(|e| panic!("Invalid {}: {}", manifest_path.display(), e));
+    let target = env::var("TARGET").unwrap();
+    let entry = select_entry(&entries, &target).ok_or_else(|| {
+        format!(
+            "{} has no bundled tesseract for target {}",
+            manifest_path.display(),
+            target
+        )
+    })?;
+    Ok((
+        tesseract_libs_dir.join(&entry.dir),
+        entry.lib.clone(),
+        entry.version.clone(),
+    ))
+}
 
-    Ok(())
+// A library built for another architecture fails at link time with
+// confusing errors, so it is pointed out here. A universal macOS library
+// matches if any of its slices does. Static archives aren't checked.
+fn check_bundled_arch(path: &Path) {
+    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let mut header = Vec::with_capacity(arch::HEADER_LEN);
+    let read = fs::File::open(path)
+        .and_then(|file| file.take(arch::HEADER_LEN as u64).read_to_end(&mut header));
+    if read.is_err() {
+        return;
+    }
+    match arch::library_archs(&header) {
+        Some(archs) if !archs.contains(&target_arch.as_str()) => println!(
+            "cargo:warning={} is built for {}, not the target's {}",
+            path.display(),
+            if archs.is_empty() {
+                "an unknown architecture".to_string()
+            } else {
+                archs.join(", ")
+            },
+            target_arch
+        ),
+        _ => {}
+    }
 }
 
-fn find_bundled_tesseract_lib() -> Vec<String> {
-    reveal_location().expect("Failed to reveal location");
-    let mut tesseract_dir = LIBS_PATH.to_string();
-    tesseract_dir.push_str("tesseract/");
-    tesseract_dir.push_str(TESSERACT_VERSION);
-    let mut tesseract_lib_dir = tesseract_dir.clone();
-    tesseract_lib_dir.push_str("/lib");
-    let mut tesseract_include_dir = tesseract_dir.clone();
-    tesseract_include_dir.push_str("/include");
+fn find_bundled_tesseract_lib() -> Result<Vec<PathBuf>, String> {
+    let (tesseract_dir, lib_name, version) = bundled_tesseract_location()?;
+    let tesseract_lib_dir = tesseract_dir.join("lib");
+    let tesseract_include_dir = tesseract_dir.join("include");
 
-    println!("cargo:rustc-link-search=native={}", tesseract_lib_dir);
-    println!("cargo:rustc-link-lib=tesseract");
+    match find_bundled_library(&tesseract_lib_dir, &lib_name) {
+        BundledLibrary::Found(path) => check_bundled_arch(&path),
+        BundledLibrary::LfsPointer(path) => return Err(format!(
+            "{} is a git-lfs pointer, not the tesseract library. Run `git lfs pull` to fetch the bundled libraries.",
+            path.display()
+        )),
+        BundledLibrary::Missing => return Err(format!(
+            "No tesseract library found in {}. If you cloned the repository, run `git lfs pull`; otherwise check that the bundled libraries were vendored.",
+            tesseract_lib_dir.display()
+        )),
+    }
+
+    println!("{}", paths::link_search(&tesseract_lib_dir));
+    link_tesseract(&lib_name);
+    emit_version_cfgs(&version);
 
-    vec![tesseract_include_dir]
+    Ok(vec![tesseract_include_dir])
 }
 
 #[cfg(all(
@@ -125,79 +407,260 @@ fn find_bundled_tesseract_lib() -> Vec<String> {
     not(target_os = "linux"),
     not(target_os = "freebsd")
 ))]
-fn find_tesseract_system_lib() -> Vec<String> {
+fn find_tesseract_system_lib() -> Result<Vec<PathBuf>, String> {
     println!("cargo:rustc-link-lib=tesseract");
-    vec![]
+    Ok(vec![])
+}
+
+const LIBCLANG_HINT: &str = "bindgen needs libclang to parse the tesseract headers. \
+Install it with `apt install libclang-dev clang` (Debian/Ubuntu), \
+`dnf install clang-devel` (Fedora), `brew install llvm` (macOS) \
+or the LLVM installer from https://releases.llvm.org (Windows). \
+If libclang is installed somewhere unusual, point LIBCLANG_PATH at the directory containing it.";
+
+// bindgen's own error rarely mentions clang, which is by far the most common
+// cause of a failure here.
+fn bindgen_failed(which: &str, err: bindgen::BindgenError) -> ! {
+    println!(
+        "cargo:warning=Unable to generate {} bindings: {}",
+        which, err
+    );
+    println!("cargo:warning={}", LIBCLANG_HINT);
+    panic!(
+        "Unable to generate {} bindings: {:?}\n{}",
+        which, err, LIBCLANG_HINT
+    )
+}
+
+// Forks of tesseract with extra C API functions can point this at their own
+// umbrella header; every `Tess*` function it declares gets a binding.
+fn capi_header() -> PathBuf {
+    println!("cargo:rerun-if-env-changed=TESSERACT_WRAPPER_HEADER");
+    let manifest_dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap());
+    let header = paths::capi_header(
+        &manifest_dir,
+        env::var_os("TESSERACT_WRAPPER_HEADER").as_deref(),
+    );
+    println!(
+        "cargo:rerun-if-changed={}",
+        paths::relative_to(&header, &manifest_dir).display()
+    );
+    header
+}
+
+// Different libclang installs ship different builtin headers (stddef.h,
+// stdint.h, ...), which can change the generated bindings. Pinning the
+// resource directory those come from makes them the same across machines.
+// A relative path is relative to this crate's directory.
+static CLANG_RESOURCE_DIR: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+fn clang_resource_dir() -> Option<&'static Path> {
+    CLANG_RESOURCE_DIR
+        .get_or_init(|| {
+            println!("cargo:rerun-if-env-changed=TESSERACT_CLANG_RESOURCE_DIR");
+            let dir =
+                env::var_os("TESSERACT_CLANG_RESOURCE_DIR").filter(|dir| !dir.is_empty())?;
+            let dir = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join(dir);
+            if !dir.join("include").is_dir() {
+                println!(
+                    "cargo:warning=TESSERACT_CLANG_RESOURCE_DIR {} has no include directory; is it clang's resource directory?",
+                    dir.display()
+                );
+            }
+            Some(dir)
+        })
+        .as_deref()
+}
+
+// Directories whose absolute paths must not end up in the generated
+// bindings, so that they are the same wherever the crate is built.
+fn host_dirs(clang_extra_include: &[PathBuf]) -> Vec<PathBuf> {
+    let mut dirs = clang_extra_include.to_vec();
+    dirs.push(PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()));
+    dirs.push(PathBuf::from(env::var_os("OUT_DIR").unwrap()));
+    dirs.extend(clang_resource_dir().map(Path::to_path_buf));
+    dirs
 }
 
-fn capi_bindings(clang_extra_include: &[String]) -> bindgen::Bindings {
+// Types of the C API that leptonica (or, for FILE, libc) defines.
+const LEPTONICA_TYPES: &[&str] = &["Boxa", "Pix", "Pixa", "_IO_FILE"];
+
+fn leptonica_sys_enabled() -> bool {
+    let enabled = env::var_os("CARGO_FEATURE_LEPTONICA_SYS").is_some();
+    if enabled && env::var_os("CARGO_FEATURE_LEPTONICA_OPAQUE").is_some() {
+        println!("cargo:warning=leptonica-opaque has no effect while leptonica-sys is enabled; disable default features to use it");
+    }
+    enabled
+}
+
+fn capi_bindings(clang_extra_include: &[PathBuf]) -> String {
+    let header = capi_header();
     let mut capi_bindings = bindgen::Builder::default()
-        .header("wrapper_capi.h")
+        .header(
+            header
+                .to_str()
+                .expect("TESSERACT_WRAPPER_HEADER is not valid UTF-8"),
+        )
         .allowlist_function("^Tess.*")
-        .blocklist_type("Boxa")
-        .blocklist_type("Pix")
-        .blocklist_type("Pixa")
-        .blocklist_type("_IO_FILE")
+        // bindgen's default, made explicit: a #[test] per complete struct
+        // checking its size, alignment and field offsets. See the README.
+        .layout_tests(true)
         .blocklist_type("_IO_codecvt")
         .blocklist_type("_IO_marker")
         .blocklist_type("_IO_wide_data");
 
+    // With leptonica-sys these come from that crate. Without it they are
+    // opaque, and callers cast their own leptonica pointers to them.
+    let leptonica_sys = leptonica_sys_enabled();
+    for name in LEPTONICA_TYPES {
+        capi_bindings = if leptonica_sys {
+            capi_bindings.blocklist_type(name)
+        } else {
+            capi_bindings.opaque_type(name)
+        };
+    }
+
+    if let Some(dir) = clang_resource_dir() {
+        capi_bindings = capi_bindings.clang_arg(paths::resource_dir_arg(dir));
+    }
     for inc in clang_extra_include {
-        capi_bindings = capi_bindings.clang_arg(format!("-I{}", *inc));
+        capi_bindings = capi_bindings.clang_arg(paths::include_arg(inc));
     }
 
-    capi_bindings
+    let generated = capi_bindings
         .generate()
-        .expect("Unable to generate capi bindings")
+        .unwrap_or_else(|e| bindgen_failed("capi", e))
+        .to_string();
+    let mut dirs = host_dirs(clang_extra_include);
+    dirs.extend(header.parent().map(Path::to_path_buf));
+    let mut bindings = paths::strip_dirs(&generated, &dirs);
+    // Lets tooling see which functions this tesseract version provides.
+    bindings.push_str(&symbols::generated_functions(&symbols::function_names(
+        &bindings,
+    )));
+    bindings
 }
 
 #[cfg(not(target_os = "macos"))]
-fn public_types_bindings(clang_extra_include: &[String]) -> String {
+fn public_types_bindings(clang_extra_include: &[PathBuf]) -> String {
+    println!("cargo:rerun-if-env-changed=TESSERACT_RUSTIFY_ENUMS");
+    println!("cargo:rerun-if-env-changed=TESSERACT_CXX_STD");
+    println!("cargo:rerun-if-changed=wrapper_public_types.hpp");
+    let cxx_std = env::var("TESSERACT_CXX_STD").ok();
+    // Only this header is C++: the C API bindings keep clang's C default.
     let mut public_types_bindings = bindgen::Builder::default()
         .header("wrapper_public_types.hpp")
-        .rustified_enum("tesseract::OcrEngineMode")
-        .rustified_enum("tesseract::Orientation")
-        .rustified_enum("tesseract::PageIteratorLevel")
-        .rustified_enum("tesseract::PageSegMode")
-        .rustified_enum("tesseract::ParagraphJustification")
-        .rustified_enum("tesseract::PolyBlockType")
-        .rustified_enum("tesseract::TextlineOrder")
-        .rustified_enum("tesseract::WritingDirection")
+        .clang_arg(public_types::cxx_std_arg(cxx_std.as_deref()))
+        .layout_tests(true)
         .blocklist_item("^kPolyBlockNames")
         .blocklist_item("^tesseract::kPolyBlockNames");
 
+    let extra_enums = env::var("TESSERACT_RUSTIFY_ENUMS").ok();
+    for name in public_types::rustified_enums(extra_enums.as_deref()) {
+        public_types_bindings = public_types_bindings.rustified_enum(&name);
+    }
+
+    if let Some(dir) = clang_resource_dir() {
+        public_types_bindings = public_types_bindings.clang_arg(paths::resource_dir_arg(dir));
+    }
     for inc in clang_extra_include {
-        public_types_bindings = public_types_bindings.clang_arg(format!("-I{}", *inc));
+        public_types_bindings = public_types_bindings.clang_arg(paths::include_arg(inc));
     }
 
-    public_types_bindings
+    let generated = public_types_bindings
         .generate()
-        .expect("Unable to generate public types bindings")
-        .to_string()
-        .replace("tesseract_", "")
+        .unwrap_or_else(|e| bindgen_failed("public types", e))
+        .to_string();
+    paths::strip_dirs(
+        &public_types::strip_namespace(&generated),
+        &host_dirs(clang_extra_include),
+    )
 }
 
 // MacOS clang is incompatible with Bindgen and constexpr
 // https://github.com/rust-lang/rust-bindgen/issues/1948
 // Hardcode the constants rather than reading them dynamically
 #[cfg(target_os = "macos")]
-fn public_types_bindings(_clang_extra_include: &[String]) -> &'static str {
+fn public_types_bindings(_clang_extra_include: &[PathBuf]) -> &'static str {
+    println!("cargo:rerun-if-env-changed=TESSERACT_RUSTIFY_ENUMS");
+    if env::var_os("TESSERACT_RUSTIFY_ENUMS").is_some() {
+        println!("cargo:warning=TESSERACT_RUSTIFY_ENUMS is ignored on macOS, where the public types are pre-generated");
+    }
+    println!("cargo:rerun-if-env-changed=TESSERACT_CXX_STD");
+    if env::var_os("TESSERACT_CXX_STD").is_some() {
+        println!("cargo:warning=TESSERACT_CXX_STD is ignored on macOS, where the public types are pre-generated");
+    }
     include_str!("src/public_types_bindings_mac.rs")
 }
 
+// With the prebuilt-5_3_4 feature, lib.rs includes the bindings under
+// prebuilt/ and bindgen isn't run at all.
+fn prebuilt_bindings() -> bool {
+    env::var_os("CARGO_FEATURE_PREBUILT_5_3_4").is_some()
+}
+
+// Prebuilt bindings for another version may not match the library's ABI, so
+// the build fails rather than risk calls with the wrong signature, also when
+// the version is unknown.
+fn check_prebuilt_version() {
+    match LINKED_VERSION.get() {
+        Some(linked) => assert!(
+            version::same_major_minor(linked, PREBUILT_VERSION),
+            "The prebuilt bindings are for tesseract {}, but tesseract {} is linked. Disable the prebuilt-5_3_4 feature to generate bindings for it.",
+            PREBUILT_VERSION,
+            linked
+        ),
+        None => panic!(
+            "The prebuilt bindings are for tesseract {}, but the build can't tell which version is linked. Set TESSERACT_VERSION to the linked version, or disable the prebuilt-5_3_4 feature to generate bindings for it.",
+            PREBUILT_VERSION
+        ),
+    }
+}
+
 fn main() {
+    println!("cargo:rerun-if-env-changed=LIBCLANG_PATH");
+    println!("cargo:rustc-check-cfg=cfg(tesseract_5)");
+    // Listing any rerun-if-changed path stops cargo from watching the whole
+    // package, so the build script's own modules are listed too.
+    println!("cargo:rerun-if-changed=build");
     // Tell cargo to tell rustc to link the system tesseract
     // and leptonica shared libraries.
-    let clang_extra_include = find_bundled_tesseract_lib();
-
-    // Write the bindings to the $OUT_DIR/bindings.rs file.
-    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
-    capi_bindings(&clang_extra_include)
-        .write_to_file(out_path.join("capi_bindings.rs"))
-        .expect("Couldn't write capi bindings!");
-    fs::write(
-        out_path.join("public_types_bindings.rs"),
-        public_types_bindings(&clang_extra_include),
+    let clang_extra_include = find_tesseract_lib();
+    emit_delay_load();
+    if prebuilt_bindings() {
+        check_prebuilt_version();
+        if let Some(path) = signatures_dump_path() {
+            symbols::write_signatures(&path, include_str!("prebuilt/capi_bindings_5_3_4.rs"))
+                .expect("Couldn't write signatures!");
+        }
+        return;
+    }
+
+    // Write the bindings to the $OUT_DIR/[prefix]capi_bindings.rs and
+    // $OUT_DIR/[prefix]public_types_bindings.rs files.
+    let out_path = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+    let prefix = bindings_prefix();
+    let dump_dir = bindings_dump_dir();
+    let capi = capi_bindings(&clang_extra_include);
+    paths::write_bindings(
+        &out_path,
+        dump_dir.as_deref(),
+        &prefix,
+        CAPI_BINDINGS_FILE,
+        &capi,
     )
-    .expect("Couldn't write public types bindings!");
+    .expect("Couldn't write capi bindings!");
+    if let Some(path) = signatures_dump_path() {
+        symbols::write_signatures(&path, &capi).expect("Couldn't write signatures!");
+    }
+    if env::var_os("CARGO_FEATURE_PUBLIC_TYPES").is_some() {
+        paths::write_bindings(
+            &out_path,
+            dump_dir.as_deref(),
+            &prefix,
+            PUBLIC_TYPES_BINDINGS_FILE,
+            &public_types_bindings(&clang_extra_include),
+        )
+        .expect("Couldn't write public types bindings!");
+    }
 }
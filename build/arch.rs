@@ -0,0 +1,152 @@
+use std::convert::TryInto;
+
+// Mach-O CPU types, and the flag marking their 64 bit variants.
+const CPU_ARCH_ABI64: u32 = 0x0100_0000;
+const CPU_TYPE_X86: u32 = 7;
+const CPU_TYPE_ARM: u32 = 12;
+
+// Fat headers are always big-endian; FAT_CIGAM is how a little-endian read
+// sees them, and the _64 variants have 64 bit offsets in their entries.
+const FAT_MAGIC: u32 = 0xcafe_babe;
+const FAT_CIGAM: u32 = 0xbeba_feca;
+const FAT_MAGIC_64: u32 = 0xcafe_babf;
+const FAT_CIGAM_64: u32 = 0xbfba_feca;
+// Java class files share FAT_MAGIC; they have a version of 45 or more where
+// a fat header has its number of slices.
+const MAX_FAT_ARCHS: u32 = 30;
+
+/// Enough of a library's start for `library_archs` to read a fat header with
+/// up to `MAX_FAT_ARCHS` slices.
+pub const HEADER_LEN: usize = 8 + 32 * MAX_FAT_ARCHS as usize;
+
+fn read_u32(bytes: &[u8], offset: usize, big_endian: bool) -> Option<u32> {
+    let bytes: [u8; 4] = bytes.get(offset..offset + 4)?.try_into().ok()?;
+    Some(if big_endian {
+        u32::from_be_bytes(bytes)
+    } else {
+        u32::from_le_bytes(bytes)
+    })
+}
+
+fn mach_o_arch(cpu_type: u32) -> Option<&'static str> {
+    match cpu_type {
+        CPU_TYPE_X86 => Some("x86"),
+        CPU_TYPE_ARM => Some("arm"),
+        t if t == CPU_TYPE_X86 | CPU_ARCH_ABI64 => Some("x86_64"),
+        t if t == CPU_TYPE_ARM | CPU_ARCH_ABI64 => Some("aarch64"),
+        _ => None,
+    }
+}
+
+fn elf_arch(machine: u16) -> Option<&'static str> {
+    match machine {
+        3 => Some("x86"),
+        40 => Some("arm"),
+        62 => Some("x86_64"),
+        183 => Some("aarch64"),
+        _ => None,
+    }
+}
+
+/// The architectures, as `CARGO_CFG_TARGET_ARCH` names them, of the shared
+/// library starting with `header`: one for an ELF or Mach-O file, one per
+/// slice for a universal (fat) Mach-O file. Unknown architectures are left
+/// out.
+///
+/// `None` for other files, e.g. static archives, whose architecture isn't
+/// in their header.
+pub fn library_archs(header: &[u8]) -> Option<Vec<&'static str>> {
+    if header.starts_with(b"\x7fELF") {
+        let big_endian = *header.get(5)? == 2;
+        let machine: [u8; 2] = header.get(18..20)?.try_into().ok()?;
+        let machine = if big_endian {
+            u16::from_be_bytes(machine)
+        } else {
+            u16::from_le_bytes(machine)
+        };
+        return Some(elf_arch(machine).into_iter().collect());
+    }
+    match read_u32(header, 0, true)? {
+        // Thin Mach-O files, whose magic is in their own byte order; Apple's
+        // targets are all little-endian.
+        0xcefa_edfe | 0xcffa_edfe => Some(
+            mach_o_arch(read_u32(header, 4, false)?)
+                .into_iter()
+                .collect(),
+        ),
+        magic @ (FAT_MAGIC | FAT_CIGAM | FAT_MAGIC_64 | FAT_CIGAM_64) => {
+            let big_endian = magic == FAT_MAGIC || magic == FAT_MAGIC_64;
+            let entry_len = if magic == FAT_MAGIC || magic == FAT_CIGAM {
+                20
+            } else {
+                32
+            };
+            let count = read_u32(header, 4, big_endian)?;
+            if count > MAX_FAT_ARCHS {
+                return None;
+            }
+            let cpu_types = (0..count as usize)
+                .map(|i| read_u32(header, 8 + i * entry_len, big_endian))
+                .collect::<Option<Vec<_>>>()?;
+            Some(cpu_types.into_iter().filter_map(mach_o_arch).collect())
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fat_header(magic: u32, cpu_types: &[u32], big_endian: bool) -> Vec<u8> {
+        let word = |value: u32| {
+            if big_endian {
+                value.to_be_bytes()
+            } else {
+                value.to_le_bytes()
+            }
+        };
+        let mut header = magic.to_be_bytes().to_vec();
+        header.extend_from_slice(&word(cpu_types.len() as u32));
+        for (i, cpu_type) in cpu_types.iter().enumerate() {
+            // cputype, cpusubtype, offset, size, align
+            for value in [*cpu_type, 0, 0x4000 * (i as u32 + 1), 0x1000, 14] {
+                header.extend_from_slice(&word(value));
+            }
+        }
+        header
+    }
+
+    #[test]
+    fn universal_binary() {
+        let header = fat_header(FAT_MAGIC, &[0x0100_0007, 0x0100_000c], true);
+        assert_eq!(library_archs(&header), Some(vec!["x86_64", "aarch64"]));
+        let header = fat_header(FAT_CIGAM, &[0x0100_000c, 0x0100_0007], false);
+        assert_eq!(library_archs(&header), Some(vec!["aarch64", "x86_64"]));
+        // Unknown slices, e.g. PowerPC, are left out.
+        let header = fat_header(FAT_MAGIC, &[18, 0x0100_000c], true);
+        assert_eq!(library_archs(&header), Some(vec!["aarch64"]));
+    }
+
+    #[test]
+    fn truncated_or_class_file() {
+        let header = fat_header(FAT_MAGIC, &[0x0100_0007, 0x0100_000c], true);
+        assert_eq!(library_archs(&header[..30]), None);
+        // A Java class file of version 52.
+        assert_eq!(library_archs(b"\xca\xfe\xba\xbe\x00\x00\x00\x34"), None);
+    }
+
+    #[test]
+    fn thin_libraries() {
+        let mut mach_o = b"\xcf\xfa\xed\xfe".to_vec();
+        mach_o.extend_from_slice(&0x0100_000cu32.to_le_bytes());
+        assert_eq!(library_archs(&mach_o), Some(vec!["aarch64"]));
+
+        let mut elf = b"\x7fELF\x02\x01\x01".to_vec();
+        elf.resize(18, 0);
+        elf.extend_from_slice(&62u16.to_le_bytes());
+        assert_eq!(library_archs(&elf), Some(vec!["x86_64"]));
+
+        assert_eq!(library_archs(b"!<arch>\n"), None);
+    }
+}
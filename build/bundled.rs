@@ -0,0 +1,130 @@
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+const LFS_POINTER_PREFIX: &[u8] = b"version https://git-lfs.github.com/spec/";
+
+#[derive(Debug, PartialEq)]
+pub enum BundledLibrary {
+    Found(PathBuf),
+    /// A git-lfs pointer file sits where the library should be.
+    LfsPointer(PathBuf),
+    Missing,
+}
+
+fn is_library_file(file_name: &str, name: &str) -> bool {
+    let prefix = format!("lib{}.", name);
+    if let Some(rest) = file_name.strip_prefix(&prefix) {
+        rest == "a" || rest == "so" || rest.starts_with("so.") || rest.ends_with("dylib")
+    } else {
+        file_name == format!("{}.lib", name)
+    }
+}
+
+/// Looks in `lib_dir` for a static or shared library called `name`, e.g.
+/// `libtesseract.so.5`, `libtesseract.dylib`, `libtesseract.a` or
+/// `tesseract.lib`.
+pub fn find_bundled_library(lib_dir: &Path, name: &str) -> BundledLibrary {
+    let entries = match fs::read_dir(lib_dir) {
+        Ok(entries) => entries,
+        Err(_) => return BundledLibrary::Missing,
+    };
+    let mut lfs_pointer = None;
+    for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+        let is_library = path
+            .file_name()
+            .and_then(|f| f.to_str())
+            .filter(|f| is_library_file(f, name))
+            .is_some();
+        if !is_library || !path.is_file() {
+            continue;
+        }
+        let mut head = Vec::with_capacity(LFS_POINTER_PREFIX.len());
+        let _ = fs::File::open(&path).and_then(|file| {
+            file.take(LFS_POINTER_PREFIX.len() as u64)
+                .read_to_end(&mut head)
+        });
+        if head == LFS_POINTER_PREFIX {
+            lfs_pointer = Some(path);
+        } else {
+            return BundledLibrary::Found(path);
+        }
+    }
+    lfs_pointer.map_or(BundledLibrary::Missing, BundledLibrary::LfsPointer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_dir(name: &str) -> PathBuf {
+        let dir = Path::new(env!("CARGO_TARGET_TMPDIR"))
+            .join("bundled")
+            .join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn library_file_names() {
+        for name in &[
+            "libtesseract.so",
+            "libtesseract.so.5",
+            "libtesseract.so.5.3.4",
+            "libtesseract.dylib",
+            "libtesseract.5.dylib",
+            "libtesseract.a",
+            "tesseract.lib",
+        ] {
+            assert!(is_library_file(name, "tesseract"), "{}", name);
+        }
+        for name in &[
+            "libtesseract.la",
+            "tesseract.pc",
+            "libleptonica.so",
+            "README",
+        ] {
+            assert!(!is_library_file(name, "tesseract"), "{}", name);
+        }
+    }
+
+    #[test]
+    fn finds_library() {
+        let dir = fixture_dir("present");
+        fs::write(dir.join("libtesseract.so.5"), b"\x7fELF").unwrap();
+        fs::write(dir.join("tesseract.pc"), b"").unwrap();
+        assert_eq!(
+            find_bundled_library(&dir, "tesseract"),
+            BundledLibrary::Found(dir.join("libtesseract.so.5"))
+        );
+    }
+
+    #[test]
+    fn missing_library() {
+        let dir = fixture_dir("empty");
+        fs::write(dir.join("tesseract.pc"), b"").unwrap();
+        assert_eq!(
+            find_bundled_library(&dir, "tesseract"),
+            BundledLibrary::Missing
+        );
+        assert_eq!(
+            find_bundled_library(&dir.join("nonexistent"), "tesseract"),
+            BundledLibrary::Missing
+        );
+    }
+
+    #[test]
+    fn lfs_pointer() {
+        let dir = fixture_dir("lfs");
+        fs::write(
+            dir.join("libtesseract.a"),
+            b"version https://git-lfs.github.com/spec/v1\noid sha256:0\nsize 1\n",
+        )
+        .unwrap();
+        assert_eq!(
+            find_bundled_library(&dir, "tesseract"),
+            BundledLibrary::LfsPointer(dir.join("libtesseract.a"))
+        );
+    }
+}
@@ -0,0 +1,131 @@
+/// The instruction linking tesseract as `name`.
+///
+/// A plain name like `tesseract` is looked up by the linker as usual
+/// (`libtesseract.so`). A file name like `libtesseract.so.5` is passed
+/// verbatim, linking that exact soname, for systems that ship only the
+/// versioned library.
+pub fn link_lib(name: &str) -> String {
+    if is_file_name(name) {
+        format!("cargo:rustc-link-lib=dylib:+verbatim={}", name)
+    } else {
+        format!("cargo:rustc-link-lib={}", name)
+    }
+}
+
+fn is_file_name(name: &str) -> bool {
+    name.contains(".so") || name.ends_with(".dylib") || name.ends_with(".lib")
+}
+
+/// The library name to link: TESSERACT_LINK_LIB_NAME if set and not empty,
+/// otherwise `default`.
+pub fn link_lib_name(custom: Option<&str>, default: &str) -> String {
+    match custom.map(str::trim) {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => default.to_string(),
+    }
+}
+
+/// The instructions for TESSERACT_LINK_LIBS, a comma-separated list of
+/// libraries that replaces the default, or `None` if it's unset or lists
+/// nothing. Each entry is linked as by `link_lib`.
+///
+/// Windows reads the variable itself, alongside its include and link paths.
+#[cfg_attr(
+    not(any(target_os = "macos", target_os = "linux", target_os = "freebsd")),
+    allow(dead_code)
+)]
+pub fn link_libs(custom: Option<&str>) -> Option<Vec<String>> {
+    let libs: Vec<String> = custom?
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(link_lib)
+        .collect();
+    if libs.is_empty() {
+        None
+    } else {
+        Some(libs)
+    }
+}
+
+/// The DLL delay-loaded by default: vcpkg's tesseract 5.3.
+pub const DEFAULT_DELAY_LOAD_DLL: &str = "tesseract53.dll";
+
+/// The instructions delay-loading `dll`, which only MSVC's linker supports,
+/// for a build with the windows-delay-load feature. Empty for any other
+/// target environment.
+///
+/// `delayload` is metadata: dependents' build scripts read it as
+/// DEP_TESSERACT_DELAYLOAD, since cargo only passes link args to this
+/// package's own binaries and tests.
+pub fn delay_load(target_env: &str, dll: &str) -> Vec<String> {
+    if target_env != "msvc" {
+        return Vec::new();
+    }
+    vec![
+        format!("cargo:rustc-link-arg=/DELAYLOAD:{}", dll),
+        "cargo:rustc-link-lib=delayimp".to_string(),
+        format!("cargo:delayload={}", dll),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_name() {
+        assert_eq!(link_lib("tesseract"), "cargo:rustc-link-lib=tesseract");
+        assert_eq!(link_lib("tesseract53"), "cargo:rustc-link-lib=tesseract53");
+    }
+
+    #[test]
+    fn versioned_soname() {
+        assert_eq!(
+            link_lib("libtesseract.so.5"),
+            "cargo:rustc-link-lib=dylib:+verbatim=libtesseract.so.5"
+        );
+    }
+
+    #[test]
+    fn name_override() {
+        assert_eq!(link_lib_name(None, "tesseract"), "tesseract");
+        assert_eq!(link_lib_name(Some(" "), "tesseract"), "tesseract");
+        assert_eq!(
+            link_lib_name(Some("libtesseract.so.5"), "tesseract"),
+            "libtesseract.so.5"
+        );
+    }
+
+    #[test]
+    fn link_libs_override() {
+        assert_eq!(link_libs(None), None);
+        assert_eq!(link_libs(Some(" , ")), None);
+        assert_eq!(
+            link_libs(Some("tesseract53")),
+            Some(vec!["cargo:rustc-link-lib=tesseract53".to_string()])
+        );
+        assert_eq!(
+            link_libs(Some("tesseractd, lept,libarchive.so.13")),
+            Some(vec![
+                "cargo:rustc-link-lib=tesseractd".to_string(),
+                "cargo:rustc-link-lib=lept".to_string(),
+                "cargo:rustc-link-lib=dylib:+verbatim=libarchive.so.13".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn delay_load_on_msvc() {
+        assert_eq!(
+            delay_load("msvc", DEFAULT_DELAY_LOAD_DLL),
+            [
+                "cargo:rustc-link-arg=/DELAYLOAD:tesseract53.dll",
+                "cargo:rustc-link-lib=delayimp",
+                "cargo:delayload=tesseract53.dll",
+            ]
+        );
+        assert!(delay_load("gnu", DEFAULT_DELAY_LOAD_DLL).is_empty());
+        assert!(delay_load("", DEFAULT_DELAY_LOAD_DLL).is_empty());
+    }
+}
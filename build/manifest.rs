@@ -0,0 +1,204 @@
+//! Reads `resources/libs/tesseract/manifest.toml`, which lists the bundled
+//! libraries:
+//!
+//! ```toml
+//! [[library]]
+//! version = "5.3.4"
+//! targets = ["x86_64-unknown-linux-gnu", "aarch64-apple-darwin"]
+//! # Directory holding lib/ and include/, relative to the manifest.
+//! # Defaults to the version.
+//! dir = "5.3.4"
+//! # Library name passed to the linker. Defaults to "tesseract".
+//! lib = "tesseract"
+//! ```
+//!
+//! Only this subset of TOML is understood: `[[library]]` tables holding
+//! string values and single-line arrays of strings. A target of `"*"`
+//! matches any target.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestEntry {
+    pub version: String,
+    pub targets: Vec<String>,
+    pub dir: String,
+    pub lib: String,
+}
+
+#[derive(Default)]
+struct PartialEntry {
+    version: Option<String>,
+    targets: Option<Vec<String>>,
+    dir: Option<String>,
+    lib: Option<String>,
+}
+
+impl PartialEntry {
+    fn finish(self, line: usize) -> Result<ManifestEntry, String> {
+        let version = self
+            .version
+            .ok_or_else(|| format!("library ending at line {} has no version", line))?;
+        let targets = self
+            .targets
+            .ok_or_else(|| format!("library ending at line {} has no targets", line))?;
+        Ok(ManifestEntry {
+            dir: self.dir.unwrap_or_else(|| version.clone()),
+            lib: self.lib.unwrap_or_else(|| "tesseract".to_string()),
+            version,
+            targets,
+        })
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn parse_string(value: &str) -> Option<String> {
+    let inner = value.strip_prefix('"')?.strip_suffix('"')?;
+    if inner.contains('"') || inner.contains('\\') {
+        None
+    } else {
+        Some(inner.to_string())
+    }
+}
+
+fn parse_string_array(value: &str) -> Option<Vec<String>> {
+    let inner = value.strip_prefix('[')?.strip_suffix(']')?.trim();
+    if inner.is_empty() {
+        return Some(Vec::new());
+    }
+    inner
+        .trim_end_matches(',')
+        .split(',')
+        .map(|item| parse_string(item.trim()))
+        .collect()
+}
+
+pub fn parse_manifest(contents: &str) -> Result<Vec<ManifestEntry>, String> {
+    let mut entries = Vec::new();
+    let mut current: Option<PartialEntry> = None;
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index + 1;
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line == "[[library]]" {
+            if let Some(entry) = current.take() {
+                entries.push(entry.finish(line_number - 1)?);
+            }
+            current = Some(PartialEntry::default());
+            continue;
+        }
+        let entry = current
+            .as_mut()
+            .ok_or_else(|| format!("line {}: expected [[library]]", line_number))?;
+        let (key, value) = line
+            .split_once('=')
+            .map(|(k, v)| (k.trim(), v.trim()))
+            .ok_or_else(|| format!("line {}: expected key = value", line_number))?;
+        let invalid = || format!("line {}: invalid value for {}", line_number, key);
+        match key {
+            "version" => entry.version = Some(parse_string(value).ok_or_else(invalid)?),
+            "targets" => entry.targets = Some(parse_string_array(value).ok_or_else(invalid)?),
+            "dir" => entry.dir = Some(parse_string(value).ok_or_else(invalid)?),
+            "lib" => entry.lib = Some(parse_string(value).ok_or_else(invalid)?),
+            _ => return Err(format!("line {}: unknown key {}", line_number, key)),
+        }
+    }
+    if let Some(entry) = current {
+        entries.push(entry.finish(contents.lines().count())?);
+    }
+    Ok(entries)
+}
+
+/// First entry listing `target`, or failing that the first listing `"*"`.
+pub fn select_entry<'a>(entries: &'a [ManifestEntry], target: &str) -> Option<&'a ManifestEntry> {
+    entries
+        .iter()
+        .find(|e| e.targets.iter().any(|t| t == target))
+        .or_else(|| entries.iter().find(|e| e.targets.iter().any(|t| t == "*")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+# Bundled tesseract builds.
+[[library]]
+version = "5.3.4"
+targets = ["x86_64-unknown-linux-gnu", "aarch64-unknown-linux-gnu"]
+dir = "5.3.4/linux"
+
+[[library]]
+version = "5.3.4"
+targets = ["aarch64-apple-darwin", "x86_64-apple-darwin",] # universal
+dir = "5.3.4/macos"
+
+[[library]]
+version = "5.3.0"
+targets = ["*"]
+lib = "tesseract53"
+"#;
+
+    #[test]
+    fn parses_entries() {
+        let entries = parse_manifest(MANIFEST).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries[1],
+            ManifestEntry {
+                version: "5.3.4".to_string(),
+                targets: vec![
+                    "aarch64-apple-darwin".to_string(),
+                    "x86_64-apple-darwin".to_string()
+                ],
+                dir: "5.3.4/macos".to_string(),
+                lib: "tesseract".to_string(),
+            }
+        );
+        assert_eq!(entries[2].dir, "5.3.0");
+        assert_eq!(entries[2].lib, "tesseract53");
+    }
+
+    #[test]
+    fn selects_by_target() {
+        let entries = parse_manifest(MANIFEST).unwrap();
+        assert_eq!(
+            select_entry(&entries, "aarch64-apple-darwin").unwrap().dir,
+            "5.3.4/macos"
+        );
+        assert_eq!(
+            select_entry(&entries, "x86_64-unknown-linux-gnu")
+                .unwrap()
+                .dir,
+            "5.3.4/linux"
+        );
+        assert_eq!(
+            select_entry(&entries, "x86_64-pc-windows-msvc")
+                .unwrap()
+                .lib,
+            "tesseract53"
+        );
+        assert_eq!(select_entry(&entries[..2], "x86_64-pc-windows-msvc"), None);
+    }
+
+    #[test]
+    fn rejects_invalid_manifests() {
+        assert!(parse_manifest("version = \"5.3.4\"").is_err());
+        assert!(parse_manifest("[[library]]\nversion = \"5.3.4\"").is_err());
+        assert!(parse_manifest("[[library]]\nversion = 5\ntargets = []").is_err());
+        assert!(
+            parse_manifest("[[library]]\nversion = \"5\"\ntargets = []\ncolor = \"red\"").is_err()
+        );
+    }
+}
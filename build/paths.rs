@@ -0,0 +1,249 @@
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_CAPI_HEADER: &str = "wrapper_capi.h";
+
+/// The clang argument adding `dir` to the include path.
+///
+/// bindgen hands each argument to libclang as-is rather than through a shell,
+/// so the path must not be quoted: spaces and parentheses are kept verbatim
+/// and quotes would end up in the path.
+pub fn include_arg(dir: &Path) -> String {
+    format!("-I{}", dir.display())
+}
+
+/// The clang argument making `dir` clang's resource directory, where it
+/// looks for its builtin headers. Unquoted, like `include_arg`.
+pub fn resource_dir_arg(dir: &Path) -> String {
+    format!("-resource-dir={}", dir.display())
+}
+
+/// The instruction adding `dir` to the native library search path. cargo
+/// takes everything after `=` as the path, so it's written verbatim, not
+/// Debug-formatted (which adds quotes and doubles Windows backslashes).
+pub fn link_search(dir: &Path) -> String {
+    format!("cargo:rustc-link-search=native={}", dir.display())
+}
+
+/// The header the C API bindings are generated from: TESSERACT_WRAPPER_HEADER
+/// if set, resolved against `manifest_dir` when relative, otherwise the
+/// crate's own `wrapper_capi.h`.
+pub fn capi_header(manifest_dir: &Path, custom: Option<&OsStr>) -> PathBuf {
+    match custom {
+        Some(header) if !header.is_empty() => manifest_dir.join(header),
+        _ => manifest_dir.join(DEFAULT_CAPI_HEADER),
+    }
+}
+
+/// Where a generated bindings file goes in OUT_DIR.
+pub fn bindings_file(out_dir: &Path, prefix: &str, name: &str) -> PathBuf {
+    out_dir.join(format!("{}{}", prefix, name))
+}
+
+/// Writes `bindings` to `out_dir` under `bindings_file`'s name and, when
+/// `dump_dir` is given, a copy to `dump_dir`, which is created if missing.
+pub fn write_bindings(
+    out_dir: &Path,
+    dump_dir: Option<&Path>,
+    prefix: &str,
+    name: &str,
+    bindings: &str,
+) -> io::Result<()> {
+    fs::write(bindings_file(out_dir, prefix, name), bindings)?;
+    if let Some(dir) = dump_dir {
+        fs::create_dir_all(dir)?;
+        fs::write(bindings_file(dir, prefix, name), bindings)?;
+    }
+    Ok(())
+}
+
+/// `path` relative to `base` when it lies inside it, for cargo instructions
+/// that cargo resolves against the package root anyway.
+pub fn relative_to(path: &Path, base: &Path) -> PathBuf {
+    path.strip_prefix(base).unwrap_or(path).to_path_buf()
+}
+
+/// Removes the absolute `dirs` from header paths in `generated`, so
+/// `<dir>/tesseract/capi.h` becomes `tesseract/capi.h`. Longer directories
+/// are stripped first, so a directory nested in another one is removed whole.
+/// Relative and empty directories are left alone.
+pub fn strip_dirs(generated: &str, dirs: &[PathBuf]) -> String {
+    let mut prefixes: Vec<String> = dirs
+        .iter()
+        .filter(|dir| dir.is_absolute())
+        .filter_map(|dir| dir.to_str())
+        .map(|dir| {
+            let dir = dir.trim_end_matches(std::path::is_separator);
+            format!("{}{}", dir, std::path::MAIN_SEPARATOR)
+        })
+        .collect();
+    prefixes.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    prefixes.dedup();
+    prefixes.iter().fold(generated.to_string(), |text, prefix| {
+        text.replace(prefix.as_str(), "")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn include_arg_is_unquoted() {
+        let dir = Path::new("/opt/Program Files (x86)/tesseract/include");
+        assert_eq!(
+            include_arg(dir),
+            "-I/opt/Program Files (x86)/tesseract/include"
+        );
+    }
+
+    #[test]
+    fn link_search_is_not_debug_formatted() {
+        let dir = Path::new(r"C:\Program Files (x86)\tesseract\lib");
+        assert_eq!(
+            link_search(dir),
+            r"cargo:rustc-link-search=native=C:\Program Files (x86)\tesseract\lib"
+        );
+    }
+
+    #[test]
+    fn capi_header_override() {
+        let manifest_dir = Path::new("/src/tesseract-sys");
+        assert_eq!(
+            capi_header(manifest_dir, None),
+            manifest_dir.join("wrapper_capi.h")
+        );
+        assert_eq!(
+            capi_header(manifest_dir, Some(OsStr::new(""))),
+            manifest_dir.join("wrapper_capi.h")
+        );
+        assert_eq!(
+            capi_header(manifest_dir, Some(OsStr::new("/fork/wrapper.h"))),
+            Path::new("/fork/wrapper.h")
+        );
+        assert_eq!(
+            capi_header(manifest_dir, Some(OsStr::new("patches/wrapper.h"))),
+            manifest_dir.join("patches/wrapper.h")
+        );
+    }
+
+    #[test]
+    fn relative_rerun_path() {
+        let manifest_dir = Path::new("/src/tesseract-sys");
+        assert_eq!(
+            relative_to(&manifest_dir.join("wrapper_capi.h"), manifest_dir),
+            Path::new("wrapper_capi.h")
+        );
+        assert_eq!(
+            relative_to(Path::new("/fork/wrapper.h"), manifest_dir),
+            Path::new("/fork/wrapper.h")
+        );
+    }
+
+    // Two checkouts of the crate, as a stand-in for building in two
+    // different working directories: once the host directories are stripped,
+    // the generated bindings must be byte-identical.
+    #[test]
+    fn bindings_identical_across_checkouts() {
+        let generated = |root: &Path| {
+            let include = root.join("resources/libs/tesseract/include");
+            let text = format!(
+                "/* automatically generated by rust-bindgen 0.64.0 */\n\
+                 // from {}\n\
+                 // see {}\n\
+                 extern \"C\" {{ pub fn TessVersion() -> *const ::std::os::raw::c_char; }}\n",
+                include.join("tesseract").join("capi.h").display(),
+                root.join("wrapper_capi.h").display(),
+            );
+            strip_dirs(&text, &[include, root.to_path_buf()])
+        };
+        let tmp = Path::new(env!("CARGO_TARGET_TMPDIR"));
+        let first = generated(&tmp.join("first checkout"));
+        let second = generated(&tmp.join("second").join("checkout (copy)"));
+        assert_eq!(first, second);
+        assert!(first.contains(&format!(
+            "from tesseract{}capi.h",
+            std::path::MAIN_SEPARATOR
+        )));
+        assert!(first.contains("see wrapper_capi.h"));
+        assert!(!first.contains(tmp.to_str().unwrap()));
+    }
+
+    // Two machines whose libclang is installed in different places, both
+    // pinned to a copy of the same resource directory: the builtin headers'
+    // paths are stripped like the include directories', so the bindings are
+    // identical.
+    #[test]
+    fn bindings_identical_with_same_resource_dir() {
+        let generated = |resource_dir: &Path| {
+            let text = format!(
+                "// from {}\n\
+                 pub type size_t = ::std::os::raw::c_ulong;\n",
+                resource_dir.join("include").join("stddef.h").display(),
+            );
+            strip_dirs(&text, &[resource_dir.to_path_buf()])
+        };
+        let tmp = Path::new(env!("CARGO_TARGET_TMPDIR"));
+        let first = tmp.join("usr/lib/llvm-14/lib/clang/14.0.0");
+        let second = tmp.join("opt/clang 14/lib/clang/14.0.0");
+        assert_eq!(generated(&first), generated(&second));
+        assert!(!generated(&first).contains(tmp.to_str().unwrap()));
+        assert_eq!(
+            resource_dir_arg(&second),
+            format!("-resource-dir={}", second.display())
+        );
+    }
+
+    #[test]
+    fn dumped_bindings() {
+        let tmp = Path::new(env!("CARGO_TARGET_TMPDIR")).join("dumped_bindings");
+        let _ = fs::remove_dir_all(&tmp);
+        let out_dir = tmp.join("out");
+        let dump_dir = tmp.join("dump");
+        fs::create_dir_all(&out_dir).unwrap();
+        write_bindings(&out_dir, None, "", "capi_bindings.rs", "// capi\n").unwrap();
+        assert!(!dump_dir.exists());
+
+        for (name, bindings) in [
+            ("capi_bindings.rs", "// capi\n"),
+            ("public_types_bindings.rs", "// public types\n"),
+        ] {
+            write_bindings(&out_dir, Some(&dump_dir), "docsrs_", name, bindings).unwrap();
+            let file = format!("docsrs_{}", name);
+            assert_eq!(fs::read_to_string(out_dir.join(&file)).unwrap(), bindings);
+            assert_eq!(fs::read_to_string(dump_dir.join(&file)).unwrap(), bindings);
+        }
+    }
+
+    #[test]
+    fn strip_dirs_ignores_relative_dirs() {
+        let text = "// wrapper_public_types.hpp\n";
+        assert_eq!(
+            strip_dirs(text, &[PathBuf::new(), PathBuf::from("wrapper")]),
+            text
+        );
+    }
+
+    #[test]
+    fn out_dir_with_spaces_and_parentheses() {
+        let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("Program Files (x86)/out dir");
+        fs::create_dir_all(&out_dir).unwrap();
+        let file = bindings_file(&out_dir, "docsrs_", "capi_bindings.rs");
+        assert_eq!(file.parent(), Some(out_dir.as_path()));
+        assert_eq!(
+            file.file_name().and_then(|f| f.to_str()),
+            Some("docsrs_capi_bindings.rs")
+        );
+        fs::write(&file, "// generated\n").unwrap();
+        assert_eq!(fs::read_to_string(&file).unwrap(), "// generated\n");
+
+        let include = out_dir.join("include");
+        assert_eq!(
+            include_arg(&include).strip_prefix("-I"),
+            Some(include.to_str().unwrap())
+        );
+    }
+}
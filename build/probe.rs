@@ -0,0 +1,218 @@
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+pub const DEFAULT_PROBE_RETRIES: u32 = 2;
+
+/// Parses TESSERACT_PROBE_RETRIES, falling back to the default when unset or
+/// invalid.
+pub fn probe_retries(value: Option<&str>) -> u32 {
+    value
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_PROBE_RETRIES)
+}
+
+pub const DEFAULT_MIN_VERSION: &str = "4.1";
+
+/// Parses TESSERACT_MIN_VERSION: the oldest tesseract the probe accepts, or
+/// `None` to accept any version when it is empty or `any`.
+pub fn min_version(value: Option<&str>) -> Option<&str> {
+    match value.map(str::trim) {
+        None => Some(DEFAULT_MIN_VERSION),
+        Some("") | Some("any") => None,
+        Some(version) => Some(version),
+    }
+}
+
+/// Where Homebrew puts tesseract's `.pc` files for `target_arch`: under
+/// `/opt/homebrew` on Apple Silicon and `/usr/local` on Intel Macs, both in
+/// the shared `lib/pkgconfig` and in the keg, which is there even if the
+/// formula isn't linked.
+pub fn homebrew_pkg_config_dirs(target_arch: &str) -> Vec<PathBuf> {
+    let prefix = if target_arch == "aarch64" {
+        "/opt/homebrew"
+    } else {
+        "/usr/local"
+    };
+    vec![
+        PathBuf::from(prefix).join("lib/pkgconfig"),
+        PathBuf::from(prefix).join("opt/tesseract/lib/pkgconfig"),
+    ]
+}
+
+/// PKG_CONFIG_PATH with `extra` appended: the directories of `current`, if
+/// set, come first so that they still win, and those of `extra` it already
+/// lists aren't repeated. `None` if there's nothing to add.
+pub fn augmented_pkg_config_path(current: Option<&OsStr>, extra: &[PathBuf]) -> Option<OsString> {
+    let mut dirs: Vec<PathBuf> = current
+        .map(env::split_paths)
+        .into_iter()
+        .flatten()
+        .collect();
+    let listed = dirs.len();
+    for dir in extra {
+        if !dirs.contains(dir) {
+            dirs.push(dir.clone());
+        }
+    }
+    if dirs.len() == listed {
+        return None;
+    }
+    env::join_paths(dirs).ok()
+}
+
+/// Whether pkg-config's stderr says the package is missing or too old, which
+/// retrying won't fix.
+pub fn is_not_found_message(stderr: &str) -> bool {
+    stderr.contains("was not found")
+        || stderr.contains("No package")
+        || stderr.contains("Requested '")
+}
+
+/// Calls `probe` until it succeeds, fails with an error `is_transient`
+/// rejects, or has been retried `retries` times. Waits `backoff` times the
+/// attempt number between attempts.
+pub fn retry<T, E>(
+    retries: u32,
+    backoff: Duration,
+    is_transient: impl Fn(&E) -> bool,
+    mut probe: impl FnMut() -> Result<T, E>,
+) -> Result<T, E> {
+    let mut attempt = 0;
+    loop {
+        match probe() {
+            Err(e) if attempt < retries && is_transient(&e) => {
+                attempt += 1;
+                println!(
+                    "cargo:warning=Probing for tesseract failed, retrying ({}/{})",
+                    attempt, retries
+                );
+                thread::sleep(backoff * attempt);
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[derive(Debug, PartialEq)]
+    enum StubError {
+        CommandFailed,
+        NotFound,
+    }
+
+    fn is_transient(e: &StubError) -> bool {
+        *e == StubError::CommandFailed
+    }
+
+    #[test]
+    fn retries_transient_failures() {
+        let calls = Cell::new(0);
+        let result = retry(3, Duration::from_millis(0), is_transient, || {
+            calls.set(calls.get() + 1);
+            if calls.get() <= 2 {
+                Err(StubError::CommandFailed)
+            } else {
+                Ok("tesseract")
+            }
+        });
+        assert_eq!(result, Ok("tesseract"));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn does_not_retry_not_found() {
+        let calls = Cell::new(0);
+        let result: Result<(), _> = retry(3, Duration::from_millis(0), is_transient, || {
+            calls.set(calls.get() + 1);
+            Err(StubError::NotFound)
+        });
+        assert_eq!(result, Err(StubError::NotFound));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn gives_up_after_retries() {
+        let calls = Cell::new(0);
+        let result: Result<(), _> = retry(2, Duration::from_millis(0), is_transient, || {
+            calls.set(calls.get() + 1);
+            Err(StubError::CommandFailed)
+        });
+        assert_eq!(result, Err(StubError::CommandFailed));
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn retries_from_env() {
+        assert_eq!(probe_retries(None), DEFAULT_PROBE_RETRIES);
+        assert_eq!(probe_retries(Some("5")), 5);
+        assert_eq!(probe_retries(Some("0")), 0);
+        assert_eq!(probe_retries(Some("many")), DEFAULT_PROBE_RETRIES);
+    }
+
+    #[test]
+    fn not_found_messages() {
+        assert!(is_not_found_message(
+            "Package tesseract was not found in the pkg-config search path."
+        ));
+        assert!(is_not_found_message(
+            "Requested 'tesseract >= 4.1' but version of tesseract is 3.05"
+        ));
+        assert!(!is_not_found_message(
+            "error while loading shared libraries"
+        ));
+    }
+
+    #[test]
+    fn homebrew_dirs_per_arch() {
+        assert_eq!(
+            homebrew_pkg_config_dirs("aarch64"),
+            [
+                PathBuf::from("/opt/homebrew/lib/pkgconfig"),
+                PathBuf::from("/opt/homebrew/opt/tesseract/lib/pkgconfig"),
+            ]
+        );
+        assert_eq!(
+            homebrew_pkg_config_dirs("x86_64"),
+            [
+                PathBuf::from("/usr/local/lib/pkgconfig"),
+                PathBuf::from("/usr/local/opt/tesseract/lib/pkgconfig"),
+            ]
+        );
+    }
+
+    #[test]
+    fn pkg_config_path_augmented() {
+        let homebrew = homebrew_pkg_config_dirs("aarch64");
+        assert_eq!(
+            augmented_pkg_config_path(None, &homebrew),
+            Some(env::join_paths(&homebrew).unwrap())
+        );
+
+        // An explicit PKG_CONFIG_PATH stays first.
+        let custom = PathBuf::from("/opt/tesseract-dev/lib/pkgconfig");
+        let current = env::join_paths([&custom, &homebrew[1]]).unwrap();
+        assert_eq!(
+            augmented_pkg_config_path(Some(&current), &homebrew),
+            Some(env::join_paths([&custom, &homebrew[1], &homebrew[0]]).unwrap())
+        );
+
+        let current = env::join_paths(&homebrew).unwrap();
+        assert_eq!(augmented_pkg_config_path(Some(&current), &homebrew), None);
+    }
+
+    #[test]
+    fn min_version_override() {
+        assert_eq!(min_version(None), Some("4.1"));
+        assert_eq!(min_version(Some("5.0")), Some("5.0"));
+        assert_eq!(min_version(Some(" 3.05\n")), Some("3.05"));
+        assert_eq!(min_version(Some("")), None);
+        assert_eq!(min_version(Some("any")), None);
+    }
+}
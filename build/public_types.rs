@@ -0,0 +1,99 @@
+/// Enums from `tesseract/publictypes.h` that are always generated as Rust
+/// enums.
+pub const DEFAULT_RUSTIFIED_ENUMS: &[&str] = &[
+    "tesseract::OcrEngineMode",
+    "tesseract::Orientation",
+    "tesseract::PageIteratorLevel",
+    "tesseract::PageSegMode",
+    "tesseract::ParagraphJustification",
+    "tesseract::PolyBlockType",
+    "tesseract::TextlineOrder",
+    "tesseract::WritingDirection",
+];
+
+/// The defaults plus the comma-separated names from TESSERACT_RUSTIFY_ENUMS,
+/// e.g. `ThresholdMethod`. Names without a namespace are looked up in
+/// `tesseract::`.
+pub fn rustified_enums(extra: Option<&str>) -> Vec<String> {
+    let mut enums: Vec<String> = DEFAULT_RUSTIFIED_ENUMS
+        .iter()
+        .map(|name| name.to_string())
+        .collect();
+    for name in extra.unwrap_or_default().split(',').map(str::trim) {
+        if name.is_empty() {
+            continue;
+        }
+        let name = if name.contains("::") {
+            name.to_string()
+        } else {
+            format!("tesseract::{}", name)
+        };
+        if !enums.contains(&name) {
+            enums.push(name);
+        }
+    }
+    enums
+}
+
+/// C++ standard `wrapper_public_types.hpp` is parsed with unless
+/// TESSERACT_CXX_STD says otherwise. Newer libstdc++ headers need C++17.
+pub const DEFAULT_CXX_STD: &str = "c++17";
+
+/// The clang `-std=` argument for the public types header. TESSERACT_CXX_STD
+/// takes a standard as clang names it, e.g. `c++20` or `gnu++17`; a bare year
+/// such as `20` means `c++20`.
+pub fn cxx_std_arg(custom: Option<&str>) -> String {
+    let std = custom.map(str::trim).filter(|std| !std.is_empty());
+    let std = match std {
+        Some(std) if std.bytes().all(|b| b.is_ascii_digit()) => format!("c++{}", std),
+        Some(std) => std.trim_start_matches("-std=").to_string(),
+        None => DEFAULT_CXX_STD.to_string(),
+    };
+    format!("-std={}", std)
+}
+
+/// Drops the `tesseract_` prefix bindgen gives items of the `tesseract`
+/// namespace, so `tesseract_PageSegMode` becomes `PageSegMode`.
+pub fn strip_namespace(bindings: &str) -> String {
+    bindings.replace("tesseract_", "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_only() {
+        assert_eq!(rustified_enums(None), DEFAULT_RUSTIFIED_ENUMS);
+        assert_eq!(rustified_enums(Some(" , ")), DEFAULT_RUSTIFIED_ENUMS);
+    }
+
+    #[test]
+    fn extra_enums() {
+        let enums = rustified_enums(Some("ThresholdMethod, tesseract::PageSegMode,foo::Bar"));
+        assert_eq!(enums.len(), DEFAULT_RUSTIFIED_ENUMS.len() + 2);
+        assert!(enums.ends_with(&[
+            "tesseract::ThresholdMethod".to_string(),
+            "foo::Bar".to_string()
+        ]));
+    }
+
+    #[test]
+    fn cxx_std() {
+        assert_eq!(cxx_std_arg(None), "-std=c++17");
+        assert_eq!(cxx_std_arg(Some(" ")), "-std=c++17");
+        assert_eq!(cxx_std_arg(Some("gnu++20")), "-std=gnu++20");
+        assert_eq!(cxx_std_arg(Some("-std=c++14")), "-std=c++14");
+        assert_eq!(cxx_std_arg(Some("20")), "-std=c++20");
+    }
+
+    #[test]
+    fn strips_prefix_of_requested_enums() {
+        let generated = "pub enum tesseract_ThresholdMethod {\n    \
+            tesseract_ThresholdMethod_Otsu = 0,\n}\n";
+        assert_eq!(
+            strip_namespace(generated),
+            "pub enum ThresholdMethod {\n    ThresholdMethod_Otsu = 0,\n}\n"
+        );
+    }
+}
@@ -0,0 +1,31 @@
+/// Sanitizers rustc supports on the targets tesseract builds for, named as in
+/// `-Zsanitizer=`.
+pub const KNOWN_SANITIZERS: &[&str] = &["address", "hwaddress", "leak", "memory", "thread"];
+
+/// The sanitizer named by TESSERACT_SANITIZER, or `None` if it is unset or
+/// empty.
+pub fn sanitizer(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unset_is_no_sanitizer() {
+        assert_eq!(sanitizer(None), None);
+        assert_eq!(sanitizer(Some(" ")), None);
+        assert_eq!(sanitizer(Some("")), None);
+    }
+
+    #[test]
+    fn sanitizer_names() {
+        for name in KNOWN_SANITIZERS {
+            assert_eq!(sanitizer(Some(name)), Some(*name));
+        }
+        assert_eq!(sanitizer(Some(" address\n")), Some("address"));
+        // Names rustc may learn later are kept as they are.
+        assert_eq!(sanitizer(Some("kernel-address")), Some("kernel-address"));
+    }
+}
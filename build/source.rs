@@ -0,0 +1,145 @@
+use std::fmt;
+
+/// Where the tesseract library is taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LibrarySource {
+    /// The prebuilt library under resources/libs.
+    Bundled,
+    /// A system install, found through pkg-config (vcpkg on Windows).
+    System,
+}
+
+impl fmt::Display for LibrarySource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            LibrarySource::Bundled => "bundled",
+            LibrarySource::System => "system",
+        })
+    }
+}
+
+/// The sources to try, in order, given TESSERACT_LINK_PREFERENCE and the
+/// sanitizer named by TESSERACT_SANITIZER. The preferred source comes first
+/// and the other is the fallback; without a preference the system library is
+/// preferred.
+///
+/// The bundled library isn't instrumented, and its statically linked C++
+/// runtime clashes with the one the sanitizer runtime interposes, so a
+/// sanitizer build only tries the system library.
+pub fn link_order(
+    preference: Option<&str>,
+    sanitizer: Option<&str>,
+) -> Result<Vec<LibrarySource>, String> {
+    let preference = preference.map(str::trim).filter(|value| !value.is_empty());
+    let preferred = match preference {
+        None | Some("system") => LibrarySource::System,
+        Some("bundled") => LibrarySource::Bundled,
+        Some(other) => {
+            return Err(format!(
+                "Invalid TESSERACT_LINK_PREFERENCE `{}`: expected `system` or `bundled`",
+                other
+            ))
+        }
+    };
+    if sanitizer.is_some() {
+        return Ok(vec![LibrarySource::System]);
+    }
+    Ok(match preferred {
+        LibrarySource::System => vec![LibrarySource::System, LibrarySource::Bundled],
+        LibrarySource::Bundled => vec![LibrarySource::Bundled, LibrarySource::System],
+    })
+}
+
+/// Sources that failed, with the reason.
+pub type Failures = Vec<(LibrarySource, String)>;
+
+/// Runs `find` on each source of `order` until one succeeds, returning that
+/// source with its result and the failures before it. If all fail, returns
+/// every failure.
+pub fn first_found<T>(
+    order: &[LibrarySource],
+    mut find: impl FnMut(LibrarySource) -> Result<T, String>,
+) -> Result<(LibrarySource, T, Failures), Failures> {
+    let mut failures = Vec::new();
+    for &source in order {
+        match find(source) {
+            Ok(found) => return Ok((source, found, failures)),
+            Err(e) => failures.push((source, e)),
+        }
+    }
+    Err(failures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use LibrarySource::{Bundled, System};
+
+    #[test]
+    fn system_preferred_by_default() {
+        assert_eq!(link_order(None, None), Ok(vec![System, Bundled]));
+        assert_eq!(link_order(Some(" "), None), Ok(vec![System, Bundled]));
+        assert_eq!(link_order(Some("system"), None), Ok(vec![System, Bundled]));
+        assert_eq!(
+            link_order(Some("bundled\n"), None),
+            Ok(vec![Bundled, System])
+        );
+        assert!(link_order(Some("static"), None).is_err());
+    }
+
+    #[test]
+    fn sanitizer_forces_system_library() {
+        assert_eq!(link_order(None, Some("address")), Ok(vec![System]));
+        assert_eq!(
+            link_order(Some("bundled"), Some("thread")),
+            Ok(vec![System])
+        );
+        assert!(link_order(Some("static"), Some("address")).is_err());
+    }
+
+    #[test]
+    fn preferred_source_found() {
+        let mut tried = Vec::new();
+        let found = first_found(&[System, Bundled], |source| {
+            tried.push(source);
+            Ok(source)
+        });
+        assert_eq!(found, Ok((System, System, vec![])));
+        assert_eq!(tried, [System]);
+    }
+
+    #[test]
+    fn falls_back_when_probe_fails() {
+        let found = first_found(&[System, Bundled], |source| match source {
+            System => Err("tesseract.pc not found".to_string()),
+            Bundled => Ok(vec!["include"]),
+        });
+        assert_eq!(
+            found,
+            Ok((
+                Bundled,
+                vec!["include"],
+                vec![(System, "tesseract.pc not found".to_string())]
+            ))
+        );
+
+        let found = first_found(&[Bundled, System], |source| match source {
+            Bundled => Err("git-lfs pointer".to_string()),
+            System => Ok(()),
+        });
+        assert_eq!(found.map(|(source, _, _)| source), Ok(System));
+    }
+
+    #[test]
+    fn all_sources_failing() {
+        let found: Result<(_, (), _), _> =
+            first_found(&[System, Bundled], |source| Err(format!("no {}", source)));
+        assert_eq!(
+            found,
+            Err(vec![
+                (System, "no system".to_string()),
+                (Bundled, "no bundled".to_string())
+            ])
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Names of the functions declared in `extern "C"` blocks of bindgen's
+/// output, sorted and without duplicates.
+pub fn function_names(generated: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut in_extern = false;
+    for line in generated.lines() {
+        if line.starts_with("extern \"C\"") {
+            in_extern = true;
+        } else if line.starts_with('}') {
+            in_extern = false;
+        } else if in_extern {
+            if let Some(signature) = line.trim_start().strip_prefix("pub fn ") {
+                let end = signature.find(['(', '<']).unwrap_or(signature.len());
+                names.push(signature[..end].trim().to_string());
+            }
+        }
+    }
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Rust source for the `GENERATED_FUNCTIONS` constant listing `names`.
+pub fn generated_functions(names: &[String]) -> String {
+    let mut source = String::from(
+        "\n/// Every C API function bound in this build, sorted. Which ones exist\n\
+         /// depends on the tesseract version the bindings were generated from.\n\
+         pub const GENERATED_FUNCTIONS: &[&str] = &[\n",
+    );
+    for name in names {
+        source.push_str(&format!("    {:?},\n", name));
+    }
+    source.push_str("];\n");
+    source
+}
+
+// The declarations in `extern "C"` blocks, after `pub fn `, each joined into
+// one line if rustfmt split it.
+fn extern_declarations(generated: &str) -> Vec<String> {
+    let mut declarations = Vec::new();
+    let mut in_extern = false;
+    let mut current: Option<String> = None;
+    for line in generated.lines() {
+        if let Some(declaration) = current.as_mut() {
+            declaration.push(' ');
+            declaration.push_str(line.trim());
+        } else if line.starts_with("extern \"C\"") {
+            in_extern = true;
+        } else if line.starts_with('}') {
+            in_extern = false;
+        } else if in_extern {
+            current = line
+                .trim_start()
+                .strip_prefix("pub fn ")
+                .map(|declaration| declaration.trim_end().to_string());
+        }
+        if current.as_ref().is_some_and(|d| d.ends_with(';')) {
+            declarations.extend(current.take());
+        }
+    }
+    declarations
+}
+
+// Splits `list` at the commas outside brackets, dropping empty items such as
+// the one after rustfmt's trailing comma.
+fn split_top_level(list: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let (mut depth, mut start, mut previous) = (0, 0, ' ');
+    for (i, c) in list.char_indices() {
+        match c {
+            '(' | '<' | '[' => depth += 1,
+            // The `>` of `->` in a function pointer type closes nothing.
+            '>' if previous == '-' => {}
+            ')' | '>' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                items.push(list[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+        previous = c;
+    }
+    items.push(list[start..].trim());
+    items.retain(|item| !item.is_empty());
+    items
+}
+
+/// One line per function declared in `extern "C"` blocks of bindgen's
+/// output, sorted by name: the name, the number of parameters and the
+/// signature, separated by tabs, e.g.
+/// `TessBaseAPIGetUTF8Text\t1\tfn(handle: *mut TessBaseAPI) -> *mut c_char`.
+/// The `::std::os::raw::` and `::std::option::` paths are left out.
+pub fn signatures(generated: &str) -> String {
+    let mut lines: Vec<(String, String)> = extern_declarations(generated)
+        .iter()
+        .filter_map(|declaration| {
+            let declaration = declaration
+                .replace("::std::os::raw::", "")
+                .replace("::std::option::", "");
+            let open = declaration.find('(')?;
+            let name = declaration[..open].trim().to_string();
+            // The parameters end at the parenthesis matching `open`.
+            let mut depth = 0;
+            let close = open
+                + declaration[open..].find(|c| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+                    depth == 0
+                })?;
+            let params = split_top_level(&declaration[open + 1..close]);
+            let ret = declaration[close + 1..]
+                .trim_end_matches(';')
+                .trim()
+                .trim_start_matches("->")
+                .trim();
+            let mut signature = format!("fn({})", params.join(", "));
+            if !ret.is_empty() {
+                signature.push_str(" -> ");
+                signature.push_str(ret);
+            }
+            let line = format!("{}\t{}\t{}\n", name, params.len(), signature);
+            Some((name, line))
+        })
+        .collect();
+    lines.sort();
+    lines.dedup();
+    let mut listing = String::from("# name\tparameters\tsignature\n");
+    lines
+        .into_iter()
+        .for_each(|(_, line)| listing.push_str(&line));
+    listing
+}
+
+/// Writes `signatures` of `generated` to `path`, creating its directory if
+/// missing.
+pub fn write_signatures(path: &Path, generated: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, signatures(generated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GENERATED: &str = r#"/* automatically generated by rust-bindgen 0.64.0 */
+
+#[repr(C)]
+pub struct TessBaseAPI {
+    _unused: [u8; 0],
+}
+impl TessBaseAPI {
+    pub fn not_a_binding(&self) {}
+}
+extern "C" {
+    pub fn TessVersion() -> *const ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn TessBaseAPIGetUTF8Text(handle: *mut TessBaseAPI) -> *mut ::std::os::raw::c_char;
+}
+extern "C" {
+    pub fn TessBaseAPICreate() -> *mut TessBaseAPI;
+    pub fn TessBaseAPIDelete(handle: *mut TessBaseAPI);
+}
+"#;
+
+    #[test]
+    fn only_extern_functions() {
+        assert_eq!(
+            function_names(GENERATED),
+            [
+                "TessBaseAPICreate",
+                "TessBaseAPIDelete",
+                "TessBaseAPIGetUTF8Text",
+                "TessVersion"
+            ]
+        );
+    }
+
+    #[test]
+    fn signatures_with_arities() {
+        let listing = signatures(GENERATED);
+        let mut lines = listing.lines();
+        assert_eq!(lines.next(), Some("# name\tparameters\tsignature"));
+        assert_eq!(
+            lines.collect::<Vec<_>>(),
+            [
+                "TessBaseAPICreate\t0\tfn() -> *mut TessBaseAPI",
+                "TessBaseAPIDelete\t1\tfn(handle: *mut TessBaseAPI)",
+                "TessBaseAPIGetUTF8Text\t1\tfn(handle: *mut TessBaseAPI) -> *mut c_char",
+                "TessVersion\t0\tfn() -> *const c_char",
+            ]
+        );
+    }
+
+    #[test]
+    fn split_declarations() {
+        let generated = "extern \"C\" {
+    pub fn TessBaseAPISetImage(
+        handle: *mut TessBaseAPI,
+        width: ::std::os::raw::c_int,
+    );
+}
+extern \"C\" {
+    pub fn TessMonitorSetProgressFunc(
+        monitor: *mut ETEXT_DESC,
+        progress: ::std::option::Option<unsafe extern \"C\" fn(a: c_int, b: c_int) -> bool>,
+    ) -> ::std::os::raw::c_int;
+}
+";
+        assert_eq!(
+            signatures(generated),
+            "# name\tparameters\tsignature\n\
+             TessBaseAPISetImage\t2\tfn(handle: *mut TessBaseAPI, width: c_int)\n\
+             TessMonitorSetProgressFunc\t2\tfn(monitor: *mut ETEXT_DESC, \
+             progress: Option<unsafe extern \"C\" fn(a: c_int, b: c_int) -> bool>) -> c_int\n"
+        );
+    }
+
+    #[test]
+    fn dumped_signatures() {
+        let path = Path::new(env!("CARGO_TARGET_TMPDIR"))
+            .join("dumped_signatures")
+            .join("signatures.tsv");
+        let _ = fs::remove_file(&path);
+        write_signatures(&path, include_str!("../prebuilt/capi_bindings_5_3_4.rs")).unwrap();
+        let listing = fs::read_to_string(&path).unwrap();
+        assert!(listing
+            .lines()
+            .any(|line| line
+                == "TessBaseAPIGetUTF8Text\t1\tfn(handle: *mut TessBaseAPI) -> *mut c_char"));
+        assert!(listing
+            .lines()
+            .any(|line| line.starts_with("TessBaseAPISetImage\t6\t")));
+        assert_eq!(
+            listing.lines().count() - 1,
+            function_names(include_str!("../prebuilt/capi_bindings_5_3_4.rs")).len()
+        );
+    }
+
+    #[test]
+    fn constant_source() {
+        let names = function_names(GENERATED);
+        let source = generated_functions(&names);
+        assert!(source.contains("pub const GENERATED_FUNCTIONS: &[&str] = &[\n"));
+        assert!(source.contains("    \"TessBaseAPIGetUTF8Text\",\n"));
+        assert!(source.ends_with("];\n"));
+        assert_eq!(generated_functions(&[]).matches('"').count(), 0);
+    }
+}
@@ -0,0 +1,99 @@
+/// cfg flags lib.rs can use to leave out wrappers of functions the linked
+/// tesseract doesn't have, e.g. `tesseract_5` for `5.3.4`.
+pub fn version_cfgs(version: &str) -> Vec<&'static str> {
+    let major = version
+        .trim()
+        .split(|c: char| !c.is_ascii_digit())
+        .next()
+        .and_then(|major| major.parse::<u32>().ok());
+    match major {
+        Some(major) if major >= 5 => vec!["tesseract_5"],
+        _ => vec![],
+    }
+}
+
+/// The version `tesseract/version.h` defines, e.g. `5.3.4`, from its
+/// `TESSERACT_MAJOR_VERSION`, `TESSERACT_MINOR_VERSION` and
+/// `TESSERACT_MICRO_VERSION` macros. The micro version defaults to 0.
+pub fn header_version(header: &str) -> Option<String> {
+    let define = |name: &str| {
+        header.lines().find_map(|line| {
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next(), words.next()) {
+                (Some("#define"), Some(macro_name), Some(value)) if macro_name == name => {
+                    value.parse::<u32>().ok()
+                }
+                _ => None,
+            }
+        })
+    };
+    let major = define("TESSERACT_MAJOR_VERSION")?;
+    let minor = define("TESSERACT_MINOR_VERSION")?;
+    let micro = define("TESSERACT_MICRO_VERSION").unwrap_or(0);
+    Some(format!("{}.{}.{}", major, minor, micro))
+}
+
+/// Whether two versions agree on major and minor, e.g. `5.3.4` and `5.3.0`.
+pub fn same_major_minor(a: &str, b: &str) -> bool {
+    match (major_minor(a), major_minor(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+fn major_minor(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?;
+    let end = minor
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(minor.len());
+    Some((major, minor[..end].parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tesseract_5() {
+        assert_eq!(version_cfgs("5.3.4"), ["tesseract_5"]);
+        assert_eq!(version_cfgs("5.0.0-alpha-20201231"), ["tesseract_5"]);
+    }
+
+    #[test]
+    fn tesseract_4() {
+        assert!(version_cfgs("4.1.1").is_empty());
+        assert!(version_cfgs("4.00.00alpha").is_empty());
+        assert!(version_cfgs("").is_empty());
+    }
+
+    #[test]
+    fn version_header() {
+        let header = "#define TESSERACT_MAJOR_VERSION 5\n\
+                      #define TESSERACT_MINOR_VERSION 3\n\
+                      #define TESSERACT_MICRO_VERSION 4\n\
+                      #define TESSERACT_VERSION_STR \"5.3.4\"\n";
+        assert_eq!(header_version(header).as_deref(), Some("5.3.4"));
+        assert_eq!(
+            header_version(
+                "#define TESSERACT_MAJOR_VERSION 4\n#define TESSERACT_MINOR_VERSION 1\n"
+            )
+            .as_deref(),
+            Some("4.1.0")
+        );
+        assert_eq!(header_version("#define TESSERACT_MAJOR_VERSION 5\n"), None);
+        assert_eq!(header_version(""), None);
+    }
+
+    #[test]
+    fn major_minor_match() {
+        assert!(same_major_minor("5.3.4", "5.3.4"));
+        assert!(same_major_minor("5.3.0", "5.3.4"));
+        assert!(same_major_minor("5.3-rc1", "5.3.4"));
+        assert!(!same_major_minor("5.4.0", "5.3.4"));
+        assert!(!same_major_minor("4.1.1", "5.3.4"));
+        assert!(!same_major_minor("5", "5.3.4"));
+        assert!(!same_major_minor("", "5.3.4"));
+    }
+}
@@ -0,0 +1,92 @@
+use super::TessBaseApi;
+use crate::TessVersion;
+use std::ffi::CStr;
+
+/// What the linked tesseract was built with, as far as can be told at run
+/// time. Returned by `TessBaseApi::build_info`.
+///
+/// The C API doesn't report build options, so apart from `version` this is
+/// best effort: the flags can say `false` for a build that has the feature
+/// but hasn't used it yet, and a `true` can come from another library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// `TessVersion()`, e.g. `5.3.4`.
+    pub version: String,
+    /// Whether the API selected an OpenCL device, which only happens in
+    /// builds with OpenCL support.
+    pub has_opencl: bool,
+    /// Whether an OpenMP runtime is loaded in the process, which tesseract
+    /// built with OpenMP links. Always `false` on Windows.
+    pub has_openmp: bool,
+}
+
+impl BuildInfo {
+    /// The major and minor numbers of `version`, e.g. `(5, 3)`.
+    pub fn major_minor(&self) -> Option<(u32, u32)> {
+        let mut parts = self.version.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?;
+        let end = minor
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(minor.len());
+        Some((major, minor[..end].parse().ok()?))
+    }
+}
+
+impl TessBaseApi {
+    /// The version and build options of the linked tesseract. Call it after
+    /// `init`: tesseract only selects an OpenCL device then.
+    pub fn build_info(&self) -> BuildInfo {
+        let version = unsafe { CStr::from_ptr(TessVersion()) }
+            .to_string_lossy()
+            .into_owned();
+        BuildInfo {
+            version,
+            has_opencl: self.opencl_device().is_some(),
+            has_openmp: openmp_loaded(),
+        }
+    }
+}
+
+// libgomp and LLVM's libomp both export omp_get_max_threads.
+#[cfg(unix)]
+fn openmp_loaded() -> bool {
+    use std::os::raw::{c_char, c_void};
+
+    extern "C" {
+        fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    }
+    // RTLD_DEFAULT: search every object loaded in the process.
+    #[cfg(any(target_os = "macos", target_os = "freebsd"))]
+    let default = -2isize as *mut c_void;
+    #[cfg(not(any(target_os = "macos", target_os = "freebsd")))]
+    let default = std::ptr::null_mut();
+    !unsafe { dlsym(default, b"omp_get_max_threads\0".as_ptr().cast()) }.is_null()
+}
+
+#[cfg(not(unix))]
+fn openmp_loaded() -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(version: &str) -> BuildInfo {
+        BuildInfo {
+            version: version.to_string(),
+            has_opencl: false,
+            has_openmp: false,
+        }
+    }
+
+    #[test]
+    fn major_minor() {
+        assert_eq!(info("5.3.4").major_minor(), Some((5, 3)));
+        assert_eq!(info("4.1.1-rc2").major_minor(), Some((4, 1)));
+        assert_eq!(info("5.0.0-alpha-20201231").major_minor(), Some((5, 0)));
+        assert_eq!(info("unknown").major_minor(), None);
+        assert_eq!(info("5").major_minor(), None);
+    }
+}
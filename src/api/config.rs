@@ -0,0 +1,162 @@
+use super::{init_error, Result, TessBaseApi};
+use crate::{OcrEngineMode, PageSegMode, TessBaseAPIInit4, TessOcrEngineMode};
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+/// Collects settings so they can be applied in a single init call.
+///
+/// ```no_run
+/// use tesseract_sys::api::TessConfig;
+/// use tesseract_sys::PageSegMode;
+///
+/// let api = TessConfig::new()
+///     .whitelist("0123456789")
+///     .psm(PageSegMode::PSM_SINGLE_LINE)
+///     .build(None, "eng")
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TessConfig {
+    configs: Vec<String>,
+    variables: Vec<(String, String)>,
+    psm: Option<PageSegMode>,
+    oem: Option<OcrEngineMode>,
+}
+
+impl TessConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A single line of digits, such as a serial number: `PSM_SINGLE_LINE`
+    /// with a whitelist of `0` to `9`. Unlike the `digits` config file, this
+    /// doesn't need tesseract's `configs` directory. The whitelist is subject
+    /// to the engine caveats of `TessBaseApi::set_char_whitelist`.
+    pub fn digits() -> Self {
+        Self::new()
+            .psm(PageSegMode::PSM_SINGLE_LINE)
+            .whitelist("0123456789")
+    }
+
+    /// An image of a single word: `PSM_SINGLE_WORD`.
+    pub fn single_word() -> Self {
+        Self::new().psm(PageSegMode::PSM_SINGLE_WORD)
+    }
+
+    /// An image of a single character: `PSM_SINGLE_CHAR`.
+    pub fn single_char() -> Self {
+        Self::new().psm(PageSegMode::PSM_SINGLE_CHAR)
+    }
+
+    /// Sets `tessedit_char_whitelist`.
+    pub fn whitelist(self, chars: &str) -> Self {
+        self.variable("tessedit_char_whitelist", chars)
+    }
+
+    /// Sets `tessedit_char_blacklist`.
+    pub fn blacklist(self, chars: &str) -> Self {
+        self.variable("tessedit_char_blacklist", chars)
+    }
+
+    /// Sets `user_defined_dpi`.
+    pub fn dpi(self, dpi: i32) -> Self {
+        self.variable("user_defined_dpi", &dpi.to_string())
+    }
+
+    /// Page segmentation mode, applied after init.
+    pub fn psm(mut self, psm: PageSegMode) -> Self {
+        self.psm = Some(psm);
+        self
+    }
+
+    /// Engine mode passed to init. Defaults to `OEM_DEFAULT`.
+    pub fn oem(mut self, oem: OcrEngineMode) -> Self {
+        self.oem = Some(oem);
+        self
+    }
+
+    /// Any other variable. Variables are applied in the order they were added,
+    /// so a later value for the same name wins.
+    pub fn variable(mut self, name: &str, value: &str) -> Self {
+        self.variables.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Config file to read at init, such as `"digits"` or `"hocr"`.
+    ///
+    /// Names are looked up in `<datapath>/configs`, then
+    /// `<datapath>/tessconfigs`, and are otherwise treated as a path.
+    pub fn config(mut self, name: &str) -> Self {
+        self.configs.push(name.to_string());
+        self
+    }
+
+    /// Creates an API and initialises it with `TessBaseAPIInit4`, passing all
+    /// config files and variables at once, then sets the page segmentation
+    /// mode.
+    pub fn build(&self, datapath: Option<&str>, language: &str) -> Result<TessBaseApi> {
+        let api = TessBaseApi::new()?;
+        let c_datapath = datapath.map(CString::new).transpose()?;
+        let c_language = CString::new(language)?;
+        let mut configs = CStringArray::new(self.configs.iter())?;
+        let mut names = CStringArray::new(self.variables.iter().map(|(name, _)| name))?;
+        let mut values = CStringArray::new(self.variables.iter().map(|(_, value)| value))?;
+        let oem = self.oem.unwrap_or(OcrEngineMode::OEM_DEFAULT);
+        let ret = unsafe {
+            TessBaseAPIInit4(
+                api.as_ptr(),
+                c_datapath.as_ref().map_or(ptr::null(), |d| d.as_ptr()),
+                c_language.as_ptr(),
+                oem as TessOcrEngineMode,
+                configs.as_mut_ptr(),
+                configs.len() as c_int,
+                names.as_mut_ptr(),
+                values.as_mut_ptr(),
+                names.len(),
+                0,
+            )
+        };
+        if ret != 0 {
+            return Err(init_error(datapath, language));
+        }
+        if let Some(psm) = self.psm {
+            api.set_page_seg_mode(psm);
+        }
+        Ok(api)
+    }
+}
+
+/// Owned copies of strings, laid out as the `char**` the init functions take.
+struct CStringArray {
+    // Owns the memory `pointers` points into.
+    _strings: Vec<CString>,
+    pointers: Vec<*mut c_char>,
+}
+
+impl CStringArray {
+    fn new<I>(strings: I) -> Result<Self>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let strings = strings
+            .into_iter()
+            .map(|s| CString::new(s.as_ref()))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        // The C API takes `char**` but does not modify the strings.
+        let pointers = strings.iter().map(|s| s.as_ptr() as *mut c_char).collect();
+        Ok(Self {
+            _strings: strings,
+            pointers,
+        })
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut *mut c_char {
+        self.pointers.as_mut_ptr()
+    }
+
+    fn len(&self) -> usize {
+        self.pointers.len()
+    }
+}
@@ -0,0 +1,546 @@
+use super::{Result, TesseractError};
+use crate::{
+    OcrEngineMode, Orientation, PageIteratorLevel, PageSegMode, PolyBlockType, TessPolyBlockType,
+    TextlineOrder, WritingDirection,
+};
+use std::convert::{Infallible, TryFrom};
+use std::fmt;
+use std::str::FromStr;
+
+// The first name of each entry is the one used by `Display`.
+const PAGE_SEG_MODES: &[(PageSegMode, &[&str])] = &[
+    (PageSegMode::PSM_OSD_ONLY, &["osd_only"]),
+    (PageSegMode::PSM_AUTO_OSD, &["auto_osd"]),
+    (PageSegMode::PSM_AUTO_ONLY, &["auto_only"]),
+    (PageSegMode::PSM_AUTO, &["auto"]),
+    (PageSegMode::PSM_SINGLE_COLUMN, &["single_column"]),
+    (
+        PageSegMode::PSM_SINGLE_BLOCK_VERT_TEXT,
+        &["single_block_vert_text"],
+    ),
+    (PageSegMode::PSM_SINGLE_BLOCK, &["single_block"]),
+    (PageSegMode::PSM_SINGLE_LINE, &["single_line"]),
+    (PageSegMode::PSM_SINGLE_WORD, &["single_word"]),
+    (PageSegMode::PSM_CIRCLE_WORD, &["circle_word"]),
+    (PageSegMode::PSM_SINGLE_CHAR, &["single_char"]),
+    (PageSegMode::PSM_SPARSE_TEXT, &["sparse_text"]),
+    (PageSegMode::PSM_SPARSE_TEXT_OSD, &["sparse_text_osd"]),
+    (PageSegMode::PSM_RAW_LINE, &["raw_line"]),
+];
+
+const OCR_ENGINE_MODES: &[(OcrEngineMode, &[&str])] = &[
+    (
+        OcrEngineMode::OEM_TESSERACT_ONLY,
+        &["legacy", "tesseract_only"],
+    ),
+    (OcrEngineMode::OEM_LSTM_ONLY, &["lstm", "lstm_only"]),
+    (
+        OcrEngineMode::OEM_TESSERACT_LSTM_COMBINED,
+        &["combined", "tesseract_lstm_combined"],
+    ),
+    (OcrEngineMode::OEM_DEFAULT, &["default"]),
+];
+
+/// Binarization method used to threshold the image before recognition, set
+/// through the `thresholding_method` variable.
+///
+/// The variable and all three methods were added in tesseract 5.0; older
+/// versions reject it.
+#[repr(i32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum ImageThresholdingMethod {
+    /// Tesseract's own Otsu implementation, the default and the only method
+    /// before 5.0.
+    Otsu = 0,
+    /// Leptonica's tiled Otsu implementation.
+    LeptonicaOtsu = 1,
+    /// Leptonica's Sauvola, which adapts to local contrast and copes better
+    /// with uneven lighting.
+    Sauvola = 2,
+}
+
+/// Script reported by `TessBaseApi::detect_orientation_script`.
+///
+/// Besides Unicode scripts, tesseract reports `Fraktur` for blackletter Latin
+/// text, and `Japanese` or `Korean` when Han characters are mixed with kana or
+/// Hangul.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum Script {
+    Latin,
+    Fraktur,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Georgian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Bengali,
+    Gurmukhi,
+    Gujarati,
+    Oriya,
+    Tamil,
+    Telugu,
+    Kannada,
+    Malayalam,
+    Sinhala,
+    Thai,
+    Lao,
+    Khmer,
+    Myanmar,
+    Tibetan,
+    Ethiopic,
+    Han,
+    Hangul,
+    Japanese,
+    Korean,
+    /// Any other name, as reported by tesseract.
+    Other(String),
+}
+
+const SCRIPTS: &[(Script, &str)] = &[
+    (Script::Latin, "Latin"),
+    (Script::Fraktur, "Fraktur"),
+    (Script::Greek, "Greek"),
+    (Script::Cyrillic, "Cyrillic"),
+    (Script::Armenian, "Armenian"),
+    (Script::Georgian, "Georgian"),
+    (Script::Hebrew, "Hebrew"),
+    (Script::Arabic, "Arabic"),
+    (Script::Devanagari, "Devanagari"),
+    (Script::Bengali, "Bengali"),
+    (Script::Gurmukhi, "Gurmukhi"),
+    (Script::Gujarati, "Gujarati"),
+    (Script::Oriya, "Oriya"),
+    (Script::Tamil, "Tamil"),
+    (Script::Telugu, "Telugu"),
+    (Script::Kannada, "Kannada"),
+    (Script::Malayalam, "Malayalam"),
+    (Script::Sinhala, "Sinhala"),
+    (Script::Thai, "Thai"),
+    (Script::Lao, "Lao"),
+    (Script::Khmer, "Khmer"),
+    (Script::Myanmar, "Myanmar"),
+    (Script::Tibetan, "Tibetan"),
+    (Script::Ethiopic, "Ethiopic"),
+    (Script::Han, "Han"),
+    (Script::Hangul, "Hangul"),
+    (Script::Japanese, "Japanese"),
+    (Script::Korean, "Korean"),
+];
+
+/// Parses tesseract's script names, ignoring case. Never fails: unknown
+/// names become `Script::Other`.
+impl FromStr for Script {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Infallible> {
+        Ok(SCRIPTS
+            .iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(s.trim()))
+            .map(|(script, _)| script.clone())
+            .unwrap_or_else(|| Script::Other(s.to_string())))
+    }
+}
+
+/// Writes the name tesseract uses.
+impl fmt::Display for Script {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Script::Other(name) => f.write_str(name),
+            script => {
+                let (_, name) = SCRIPTS.iter().find(|(s, _)| s == script).unwrap();
+                f.write_str(name)
+            }
+        }
+    }
+}
+
+/// Layout of the pixels passed to `TessBaseApi::set_image_fmt`.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum PixelFormat {
+    /// One bit per pixel, packed eight to a byte with the most significant
+    /// bit first. Unlike leptonica's 1 bpp images, a set bit is white. Each
+    /// line starts on a new byte, so a line's last byte may be partly unused.
+    Binary,
+    /// One byte of grey per pixel.
+    Grayscale,
+    /// Three bytes per pixel: red, green and blue.
+    Rgb,
+    /// Four bytes per pixel: red, green, blue and alpha. Tesseract ignores the
+    /// alpha channel.
+    Rgba,
+}
+
+impl PixelFormat {
+    /// The `bytes_per_pixel` argument of `TessBaseAPISetImage`, which is 0 for
+    /// binary images.
+    pub fn bytes_per_pixel(self) -> i32 {
+        match self {
+            PixelFormat::Binary => 0,
+            PixelFormat::Grayscale => 1,
+            PixelFormat::Rgb => 3,
+            PixelFormat::Rgba => 4,
+        }
+    }
+
+    /// Bytes in a tightly packed line of `width` pixels, or `None` if that
+    /// overflows.
+    pub fn bytes_per_line(self, width: i32) -> Option<i32> {
+        match self {
+            PixelFormat::Binary => width.checked_add(7).map(|bits| bits / 8),
+            _ => width.checked_mul(self.bytes_per_pixel()),
+        }
+    }
+}
+
+// Indexed by discriminant.
+const POLY_BLOCK_TYPES: &[PolyBlockType] = &[
+    PolyBlockType::PT_UNKNOWN,
+    PolyBlockType::PT_FLOWING_TEXT,
+    PolyBlockType::PT_HEADING_TEXT,
+    PolyBlockType::PT_PULLOUT_TEXT,
+    PolyBlockType::PT_EQUATION,
+    PolyBlockType::PT_INLINE_EQUATION,
+    PolyBlockType::PT_TABLE,
+    PolyBlockType::PT_VERTICAL_TEXT,
+    PolyBlockType::PT_CAPTION_TEXT,
+    PolyBlockType::PT_FLOWING_IMAGE,
+    PolyBlockType::PT_HEADING_IMAGE,
+    PolyBlockType::PT_PULLOUT_IMAGE,
+    PolyBlockType::PT_HORZ_LINE,
+    PolyBlockType::PT_VERT_LINE,
+    PolyBlockType::PT_NOISE,
+];
+
+/// Converts the C API's block type, mapping unknown values to `PT_UNKNOWN`.
+pub(crate) fn poly_block_type(raw: TessPolyBlockType) -> PolyBlockType {
+    POLY_BLOCK_TYPES
+        .get(raw as usize)
+        .copied()
+        .unwrap_or(PolyBlockType::PT_UNKNOWN)
+}
+
+/// Accepts the numeric form used by the tesseract CLI, any of the names in
+/// `table`, or the variant name itself (`PSM_AUTO`), ignoring case and
+/// treating `-` as `_`.
+fn parse<T: Copy>(
+    s: &str,
+    table: &[(T, &[&'static str])],
+    prefix: &str,
+    discriminant: impl Fn(T) -> u32,
+    what: &'static str,
+) -> Result<T> {
+    let normalized = s.trim().to_ascii_lowercase().replace('-', "_");
+    let name = normalized.strip_prefix(prefix).unwrap_or(&normalized);
+    let number = name.parse::<u32>().ok();
+    table
+        .iter()
+        .find(|(value, names)| number == Some(discriminant(*value)) || names.contains(&name))
+        .map(|(value, _)| *value)
+        .ok_or_else(|| TesseractError::InvalidEnumValue {
+            name: what,
+            value: s.to_string(),
+        })
+}
+
+fn name_of<T: PartialEq>(value: T, table: &[(T, &'static [&'static str])]) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(v, _)| *v == value)
+        .map(|(_, names)| names[0])
+}
+
+/// Parses `"3"`, `"auto"` or `"PSM_AUTO"` into `PSM_AUTO`, and so on.
+impl FromStr for PageSegMode {
+    type Err = TesseractError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse(
+            s,
+            PAGE_SEG_MODES,
+            "psm_",
+            |mode| mode as u32,
+            "page segmentation mode",
+        )
+    }
+}
+
+/// Writes the friendly name, e.g. `auto` for `PSM_AUTO`. `PSM_COUNT` is not a
+/// real mode and is written as its number.
+impl fmt::Display for PageSegMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match name_of(*self, PAGE_SEG_MODES) {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}", *self as u32),
+        }
+    }
+}
+
+/// Parses `"1"`, `"lstm"`, `"lstm_only"` or `"OEM_LSTM_ONLY"` into
+/// `OEM_LSTM_ONLY`, and so on.
+impl FromStr for OcrEngineMode {
+    type Err = TesseractError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse(
+            s,
+            OCR_ENGINE_MODES,
+            "oem_",
+            |mode| mode as u32,
+            "OCR engine mode",
+        )
+    }
+}
+
+/// Writes the friendly name, e.g. `lstm` for `OEM_LSTM_ONLY`. `OEM_COUNT` is
+/// not a real mode and is written as its number.
+impl fmt::Display for OcrEngineMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match name_of(*self, OCR_ENGINE_MODES) {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}", *self as u32),
+        }
+    }
+}
+
+const PAGE_ITERATOR_LEVELS: &[PageIteratorLevel] = &[
+    PageIteratorLevel::RIL_BLOCK,
+    PageIteratorLevel::RIL_PARA,
+    PageIteratorLevel::RIL_TEXTLINE,
+    PageIteratorLevel::RIL_WORD,
+    PageIteratorLevel::RIL_SYMBOL,
+];
+
+const ORIENTATIONS: &[Orientation] = &[
+    Orientation::ORIENTATION_PAGE_UP,
+    Orientation::ORIENTATION_PAGE_RIGHT,
+    Orientation::ORIENTATION_PAGE_DOWN,
+    Orientation::ORIENTATION_PAGE_LEFT,
+];
+
+const WRITING_DIRECTIONS: &[WritingDirection] = &[
+    WritingDirection::WRITING_DIRECTION_LEFT_TO_RIGHT,
+    WritingDirection::WRITING_DIRECTION_RIGHT_TO_LEFT,
+    WritingDirection::WRITING_DIRECTION_TOP_TO_BOTTOM,
+];
+
+const TEXTLINE_ORDERS: &[TextlineOrder] = &[
+    TextlineOrder::TEXTLINE_ORDER_LEFT_TO_RIGHT,
+    TextlineOrder::TEXTLINE_ORDER_RIGHT_TO_LEFT,
+    TextlineOrder::TEXTLINE_ORDER_TOP_TO_BOTTOM,
+];
+
+/// The value of `values` whose discriminant is `raw`, without transmuting a
+/// value the enum may not have.
+fn from_discriminant<T: Copy>(
+    raw: i32,
+    values: impl IntoIterator<Item = T>,
+    discriminant: impl Fn(T) -> i32,
+    what: &'static str,
+) -> Result<T> {
+    values
+        .into_iter()
+        .find(|value| discriminant(*value) == raw)
+        .ok_or_else(|| TesseractError::InvalidEnumValue {
+            name: what,
+            value: raw.to_string(),
+        })
+}
+
+// The `*_COUNT` variants aren't real values and are rejected too.
+macro_rules! try_from_i32 {
+    ($ty:ty, $values:expr, $what:expr) => {
+        /// Converts a raw value from the C API, failing with
+        /// `InvalidEnumValue` if it isn't one of the enum's values.
+        impl TryFrom<i32> for $ty {
+            type Error = TesseractError;
+
+            fn try_from(raw: i32) -> Result<Self> {
+                from_discriminant(raw, $values, |value: $ty| value as i32, $what)
+            }
+        }
+    };
+}
+
+try_from_i32!(
+    PageSegMode,
+    PAGE_SEG_MODES.iter().map(|(mode, _)| *mode),
+    "page segmentation mode"
+);
+try_from_i32!(
+    OcrEngineMode,
+    OCR_ENGINE_MODES.iter().map(|(mode, _)| *mode),
+    "OCR engine mode"
+);
+try_from_i32!(
+    PageIteratorLevel,
+    PAGE_ITERATOR_LEVELS.iter().copied(),
+    "page iterator level"
+);
+try_from_i32!(Orientation, ORIENTATIONS.iter().copied(), "orientation");
+try_from_i32!(
+    WritingDirection,
+    WRITING_DIRECTIONS.iter().copied(),
+    "writing direction"
+);
+try_from_i32!(
+    TextlineOrder,
+    TEXTLINE_ORDERS.iter().copied(),
+    "textline order"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_round_trip() {
+        for (script, name) in SCRIPTS {
+            assert_eq!(name.parse::<Script>().unwrap(), *script);
+            assert_eq!(script.to_string(), *name);
+        }
+        assert_eq!("latin".parse::<Script>().unwrap(), Script::Latin);
+        let other = "Klingon".parse::<Script>().unwrap();
+        assert_eq!(other, Script::Other("Klingon".to_string()));
+        assert_eq!(other.to_string(), "Klingon");
+    }
+
+    #[test]
+    fn pixel_format_bytes_per_line() {
+        assert_eq!(PixelFormat::Binary.bytes_per_line(1), Some(1));
+        assert_eq!(PixelFormat::Binary.bytes_per_line(8), Some(1));
+        assert_eq!(PixelFormat::Binary.bytes_per_line(9), Some(2));
+        assert_eq!(PixelFormat::Grayscale.bytes_per_line(10), Some(10));
+        assert_eq!(PixelFormat::Rgb.bytes_per_line(10), Some(30));
+        assert_eq!(PixelFormat::Rgba.bytes_per_line(10), Some(40));
+        assert_eq!(PixelFormat::Rgba.bytes_per_line(i32::MAX), None);
+    }
+
+    #[test]
+    fn page_seg_mode_round_trip() {
+        for (mode, names) in PAGE_SEG_MODES {
+            assert_eq!(mode.to_string().parse::<PageSegMode>().unwrap(), *mode);
+            assert_eq!(
+                (*mode as u32).to_string().parse::<PageSegMode>().unwrap(),
+                *mode
+            );
+            for name in *names {
+                assert_eq!(name.parse::<PageSegMode>().unwrap(), *mode);
+            }
+            assert_eq!(format!("{:?}", mode).parse::<PageSegMode>().unwrap(), *mode);
+        }
+        assert_eq!("3".parse::<PageSegMode>().unwrap(), PageSegMode::PSM_AUTO);
+        assert_eq!(
+            "Single-Line".parse::<PageSegMode>().unwrap(),
+            PageSegMode::PSM_SINGLE_LINE
+        );
+    }
+
+    #[test]
+    fn ocr_engine_mode_round_trip() {
+        for (mode, names) in OCR_ENGINE_MODES {
+            assert_eq!(mode.to_string().parse::<OcrEngineMode>().unwrap(), *mode);
+            assert_eq!(
+                (*mode as u32).to_string().parse::<OcrEngineMode>().unwrap(),
+                *mode
+            );
+            for name in *names {
+                assert_eq!(name.parse::<OcrEngineMode>().unwrap(), *mode);
+            }
+            assert_eq!(
+                format!("{:?}", mode).parse::<OcrEngineMode>().unwrap(),
+                *mode
+            );
+        }
+        assert_eq!(
+            "lstm".parse::<OcrEngineMode>().unwrap(),
+            OcrEngineMode::OEM_LSTM_ONLY
+        );
+    }
+
+    #[test]
+    fn poly_block_types() {
+        for (i, block_type) in POLY_BLOCK_TYPES.iter().enumerate() {
+            assert_eq!(*block_type as usize, i);
+        }
+        assert_eq!(poly_block_type(1), PolyBlockType::PT_FLOWING_TEXT);
+        assert_eq!(poly_block_type(99), PolyBlockType::PT_UNKNOWN);
+    }
+
+    #[test]
+    fn unknown_modes() {
+        for bad in &["", "14", "count", "autox", "-1"] {
+            let err = bad.parse::<PageSegMode>().unwrap_err();
+            assert!(err.to_string().contains("page segmentation mode"));
+        }
+        for bad in &["4", "neural", "oem_"] {
+            let err = bad.parse::<OcrEngineMode>().unwrap_err();
+            assert!(err.to_string().contains("OCR engine mode"));
+        }
+    }
+
+    #[test]
+    fn try_from_raw() {
+        assert_eq!(PageSegMode::try_from(3).unwrap(), PageSegMode::PSM_AUTO);
+        assert_eq!(
+            PageSegMode::try_from(13).unwrap(),
+            PageSegMode::PSM_RAW_LINE
+        );
+        assert!(PageSegMode::try_from(14).is_err());
+
+        assert_eq!(
+            OcrEngineMode::try_from(1).unwrap(),
+            OcrEngineMode::OEM_LSTM_ONLY
+        );
+        assert_eq!(
+            OcrEngineMode::try_from(3).unwrap(),
+            OcrEngineMode::OEM_DEFAULT
+        );
+        assert!(OcrEngineMode::try_from(4).is_err());
+
+        assert_eq!(
+            PageIteratorLevel::try_from(3).unwrap(),
+            PageIteratorLevel::RIL_WORD
+        );
+        assert_eq!(
+            PageIteratorLevel::try_from(4).unwrap(),
+            PageIteratorLevel::RIL_SYMBOL
+        );
+        assert!(PageIteratorLevel::try_from(5).is_err());
+
+        assert_eq!(
+            Orientation::try_from(0).unwrap(),
+            Orientation::ORIENTATION_PAGE_UP
+        );
+        assert_eq!(
+            Orientation::try_from(3).unwrap(),
+            Orientation::ORIENTATION_PAGE_LEFT
+        );
+        assert!(Orientation::try_from(-1).is_err());
+
+        assert_eq!(
+            WritingDirection::try_from(1).unwrap(),
+            WritingDirection::WRITING_DIRECTION_RIGHT_TO_LEFT
+        );
+        assert_eq!(
+            WritingDirection::try_from(2).unwrap(),
+            WritingDirection::WRITING_DIRECTION_TOP_TO_BOTTOM
+        );
+        assert!(WritingDirection::try_from(3).is_err());
+
+        assert_eq!(
+            TextlineOrder::try_from(0).unwrap(),
+            TextlineOrder::TEXTLINE_ORDER_LEFT_TO_RIGHT
+        );
+        assert_eq!(
+            TextlineOrder::try_from(2).unwrap(),
+            TextlineOrder::TEXTLINE_ORDER_TOP_TO_BOTTOM
+        );
+        let err = TextlineOrder::try_from(i32::MAX).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("unknown textline order `{}`", i32::MAX)
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use std::error::Error;
+use std::ffi::NulError;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+pub type Result<T> = std::result::Result<T, TesseractError>;
+
+#[derive(Debug)]
+pub enum TesseractError {
+    /// `TessBaseAPICreate` returned null.
+    CreateError,
+    /// Tesseract failed to initialise.
+    InitError,
+    /// The `.traineddata` file for `lang` is not in `datapath`.
+    MissingLanguageData { lang: String, datapath: PathBuf },
+    /// `init_from_tessdata_dir` found no `.traineddata` file to load in
+    /// `dir`.
+    NoLanguageData { dir: PathBuf },
+    /// Initialising without a datapath failed because tesseract could not
+    /// find its data.
+    TessdataNotFound { tessdata_prefix: Option<PathBuf> },
+    /// A string or path argument contained an interior NUL byte, so it
+    /// couldn't be passed to tesseract. Every method taking one reports this
+    /// rather than panicking or truncating the argument.
+    NulError(NulError),
+    /// The image passed to `set_image` does not match its dimensions.
+    InvalidImage(String),
+    /// Recognition failed.
+    RecognizeError,
+    /// `recognize_cancellable`'s flag was set before recognition finished.
+    Cancelled,
+    /// Tesseract returned no text.
+    GetTextError,
+    /// Orientation and script detection failed.
+    DetectOrientationScriptError,
+    /// A value could not be converted to one of tesseract's enums.
+    InvalidEnumValue { name: &'static str, value: String },
+    /// Tesseract has no variable called `name`, or rejected the value.
+    SetVariableError { name: String, value: String },
+    /// `ocr_to_file` could not read the input or write the output.
+    ProcessPagesError,
+    /// `print_variables_to_file` could not write to `path`.
+    PrintVariablesError { path: PathBuf },
+    /// Tesseract failed to create a `Renderer`.
+    RendererError,
+    /// Copying rendered output failed.
+    IoError(io::Error),
+    /// Leptonica failed to encode or write an image.
+    ImageWriteError,
+    /// Leptonica couldn't decode the bytes passed to `Pix::read_mem`, e.g.
+    /// because they aren't in an image format it supports.
+    DecodeError,
+    /// `hocr_validated` was called without a source resolution: the image
+    /// carried none and `set_source_resolution` wasn't called.
+    MissingDpi,
+}
+
+impl fmt::Display for TesseractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TesseractError::CreateError => write!(f, "failed to create a tesseract API"),
+            TesseractError::InitError => write!(f, "failed to initialise tesseract"),
+            TesseractError::MissingLanguageData { lang, datapath } => write!(
+                f,
+                "no language data for `{}`: {} not found; download it from \
+                 https://github.com/tesseract-ocr/tessdata",
+                lang,
+                datapath.join(format!("{}.traineddata", lang)).display()
+            ),
+            TesseractError::NoLanguageData { dir } => {
+                write!(f, "no .traineddata files in {}", dir.display())
+            }
+            TesseractError::TessdataNotFound {
+                tessdata_prefix: Some(prefix),
+            } => write!(
+                f,
+                "could not find tessdata using TESSDATA_PREFIX={}",
+                prefix.display()
+            ),
+            TesseractError::TessdataNotFound {
+                tessdata_prefix: None,
+            } => write!(
+                f,
+                "could not find tessdata; set TESSDATA_PREFIX to the tessdata directory"
+            ),
+            TesseractError::NulError(e) => write!(f, "invalid string argument: {}", e),
+            TesseractError::InvalidImage(reason) => write!(f, "invalid image: {}", reason),
+            TesseractError::RecognizeError => write!(f, "recognition failed"),
+            TesseractError::Cancelled => write!(f, "recognition was cancelled"),
+            TesseractError::GetTextError => write!(f, "failed to get text from tesseract"),
+            TesseractError::DetectOrientationScriptError => {
+                write!(f, "failed to detect orientation and script")
+            }
+            TesseractError::InvalidEnumValue { name, value } => {
+                write!(f, "unknown {} `{}`", name, value)
+            }
+            TesseractError::SetVariableError { name, value } => {
+                write!(f, "failed to set variable `{}` to `{}`", name, value)
+            }
+            TesseractError::ProcessPagesError => write!(f, "failed to process pages"),
+            TesseractError::PrintVariablesError { path } => {
+                write!(f, "failed to print variables to {}", path.display())
+            }
+            TesseractError::RendererError => write!(f, "failed to create a result renderer"),
+            TesseractError::IoError(e) => write!(f, "I/O error: {}", e),
+            TesseractError::ImageWriteError => write!(f, "failed to write image"),
+            TesseractError::DecodeError => write!(f, "failed to decode image"),
+            TesseractError::MissingDpi => write!(
+                f,
+                "the image has no resolution; call set_source_resolution after setting it"
+            ),
+        }
+    }
+}
+
+impl Error for TesseractError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TesseractError::NulError(e) => Some(e),
+            TesseractError::IoError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<NulError> for TesseractError {
+    fn from(e: NulError) -> Self {
+        TesseractError::NulError(e)
+    }
+}
+
+impl From<io::Error> for TesseractError {
+    fn from(e: io::Error) -> Self {
+        TesseractError::IoError(e)
+    }
+}
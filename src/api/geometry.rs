@@ -0,0 +1,126 @@
+//! Geometry shared by the wrappers.
+//!
+//! Coordinates are image pixels with the origin at the top left corner of the
+//! image, x growing to the right and y growing downwards. The C API describes
+//! rectangles as left/top/right/bottom where right and bottom are exclusive;
+//! `Rect` stores width and height instead, and `from_ltrb`/`to_ltrb` convert.
+
+/// A pixel position in image coordinates.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Axis-aligned rectangle in image pixel coordinates.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub left: i32,
+    pub top: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Rect {
+    /// From tesseract's left/top/right/bottom form, where right and bottom
+    /// are exclusive.
+    pub fn from_ltrb(left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        Self {
+            left,
+            top,
+            width: right - left,
+            height: bottom - top,
+        }
+    }
+
+    /// Tesseract's left/top/right/bottom form, where right and bottom are
+    /// exclusive.
+    pub fn to_ltrb(&self) -> (i32, i32, i32, i32) {
+        (self.left, self.top, self.right(), self.bottom())
+    }
+
+    /// Exclusive right edge.
+    pub fn right(&self) -> i32 {
+        self.left + self.width
+    }
+
+    /// Exclusive bottom edge.
+    pub fn bottom(&self) -> i32 {
+        self.top + self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+
+    /// Whether `point` is inside, counting the left and top edges but not the
+    /// right and bottom ones.
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= self.left
+            && point.x < self.right()
+            && point.y >= self.top
+            && point.y < self.bottom()
+    }
+
+    /// Whether the rectangles share at least one pixel. Rectangles that only
+    /// touch along an edge don't intersect.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.left < other.right()
+            && other.left < self.right()
+            && self.top < other.bottom()
+            && other.top < self.bottom()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ltrb_round_trip() {
+        let rect = Rect::from_ltrb(10, 20, 110, 70);
+        assert_eq!(
+            rect,
+            Rect {
+                left: 10,
+                top: 20,
+                width: 100,
+                height: 50
+            }
+        );
+        assert_eq!((rect.right(), rect.bottom()), (110, 70));
+        assert_eq!(rect.to_ltrb(), (10, 20, 110, 70));
+        assert!(Rect::from_ltrb(5, 5, 5, 9).is_empty());
+    }
+
+    #[test]
+    fn contains() {
+        let rect = Rect::from_ltrb(10, 20, 110, 70);
+        assert!(rect.contains(Point::new(10, 20)));
+        assert!(rect.contains(Point::new(109, 69)));
+        assert!(!rect.contains(Point::new(110, 69)));
+        assert!(!rect.contains(Point::new(109, 70)));
+        assert!(!rect.contains(Point::new(9, 30)));
+        assert!(!Rect::from_ltrb(0, 0, 0, 0).contains(Point::new(0, 0)));
+    }
+
+    #[test]
+    fn intersects() {
+        let rect = Rect::from_ltrb(10, 20, 110, 70);
+        assert!(rect.intersects(&Rect::from_ltrb(100, 60, 200, 200)));
+        assert!(rect.intersects(&Rect::from_ltrb(0, 0, 500, 500)));
+        assert!(!rect.intersects(&Rect::from_ltrb(110, 20, 200, 70)));
+        assert!(!rect.intersects(&Rect::from_ltrb(10, 70, 110, 100)));
+        assert!(!rect.intersects(&Rect::from_ltrb(50, 30, 50, 40)));
+    }
+}
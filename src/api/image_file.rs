@@ -0,0 +1,87 @@
+use super::{path_to_cstring, Pix, Result, TessBaseApi, TesseractError};
+use leptonica_sys::{
+    lept_free, pixReadMem, pixWrite, pixWriteMem, IFF_JFIF_JPEG, IFF_PNG, IFF_TIFF, IFF_TIFF_G4,
+};
+use std::path::Path;
+use std::{ptr, slice};
+
+/// File formats `Pix` can be written in.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    /// Uncompressed TIFF.
+    Tiff,
+    /// TIFF with CCITT group 4 compression; 1 bit images only.
+    TiffG4,
+    Jpeg,
+}
+
+impl ImageFormat {
+    fn as_raw(self) -> i32 {
+        (match self {
+            ImageFormat::Png => IFF_PNG,
+            ImageFormat::Tiff => IFF_TIFF,
+            ImageFormat::TiffG4 => IFF_TIFF_G4,
+            ImageFormat::Jpeg => IFF_JFIF_JPEG,
+        }) as i32
+    }
+}
+
+/// Bytes in each line of a leptonica image `width` pixels wide with `depth`
+/// bits per pixel: 4 times what `pixGetWpl` returns.
+///
+/// Leptonica pads every line to a whole number of 32 bit words, so this is
+/// often more than `width * depth / 8` rounded up: a 1 bpp line 100 pixels
+/// wide takes 16 bytes, not 13, and an 8 bpp line 101 pixels wide takes 104.
+/// It is the `bytes_per_line` to pass to `TessBaseAPISetImage` for a copy of
+/// the image's data. Note that leptonica also keeps the pixels of each word
+/// in the machine's byte order, so on little-endian machines the bytes of an
+/// image under 32 bpp have to be swapped within each word first
+/// (`pixEndianByteSwap`) for the raw path to see them in order.
+pub fn pix_bytes_per_line(width: u32, depth: u32) -> usize {
+    let bits = u64::from(width) * u64::from(depth);
+    ((bits + 31) / 32 * 4) as usize
+}
+
+impl Pix {
+    /// Decodes an encoded image, in any format leptonica reads (PNG, JPEG,
+    /// TIFF, ...), with leptonica's `pixReadMem`.
+    pub fn read_mem(bytes: &[u8]) -> Result<Self> {
+        unsafe { Pix::from_raw(pixReadMem(bytes.as_ptr(), bytes.len())) }
+            .ok_or(TesseractError::DecodeError)
+    }
+
+    /// Writes the image to `path` with leptonica's `pixWrite`.
+    pub fn write_to_path(&self, path: &Path, format: ImageFormat) -> Result<()> {
+        let c_path = path_to_cstring(path)?;
+        if unsafe { pixWrite(c_path.as_ptr(), self.as_ptr(), format.as_raw()) } == 0 {
+            Ok(())
+        } else {
+            Err(TesseractError::ImageWriteError)
+        }
+    }
+
+    /// Encodes the image in memory with leptonica's `pixWriteMem`.
+    pub fn write_to_mem(&self, format: ImageFormat) -> Result<Vec<u8>> {
+        let mut data = ptr::null_mut();
+        let mut size = 0;
+        let ret = unsafe { pixWriteMem(&mut data, &mut size, self.as_ptr(), format.as_raw()) };
+        if ret != 0 || data.is_null() {
+            return Err(TesseractError::ImageWriteError);
+        }
+        let bytes = unsafe { slice::from_raw_parts(data, size) }.to_vec();
+        unsafe { lept_free(data.cast()) };
+        Ok(bytes)
+    }
+}
+
+impl TessBaseApi {
+    /// Recognises an encoded image, such as the bytes of a PNG or JPEG file,
+    /// and returns its text: `Pix::read_mem` followed by `ocr_pix`. Nothing
+    /// touches the filesystem, and the decoded image is freed before this
+    /// returns.
+    pub fn ocr_image_bytes(&mut self, bytes: &[u8]) -> Result<String> {
+        let pix = Pix::read_mem(bytes)?;
+        self.ocr_pix(&pix)
+    }
+}
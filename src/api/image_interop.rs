@@ -0,0 +1,93 @@
+//! Conversions between `Rect` and the `image` crate's `(x, y, width, height)`
+//! regions, as taken by `image::imageops::crop` and `DynamicImage::crop_imm`.
+
+use super::Rect;
+use image::{DynamicImage, GenericImageView};
+use std::convert::TryFrom;
+
+impl From<Rect> for (u32, u32, u32, u32) {
+    /// The part of `rect` with non-negative coordinates, as `(x, y, width,
+    /// height)`. An empty rectangle becomes a zero-sized region.
+    fn from(rect: Rect) -> Self {
+        let left = rect.left.max(0);
+        let top = rect.top.max(0);
+        let width = (rect.right() - left).max(0);
+        let height = (rect.bottom() - top).max(0);
+        (left as u32, top as u32, width as u32, height as u32)
+    }
+}
+
+impl From<(u32, u32, u32, u32)> for Rect {
+    /// From `(x, y, width, height)`. The edges are clamped to `i32::MAX`, so
+    /// a region reaching past it is cut off there.
+    fn from((x, y, width, height): (u32, u32, u32, u32)) -> Self {
+        let clamp = |value: u32| i32::try_from(value).unwrap_or(i32::MAX);
+        let (left, top) = (clamp(x), clamp(y));
+        Rect {
+            left,
+            top,
+            width: clamp(width).min(i32::MAX - left),
+            height: clamp(height).min(i32::MAX - top),
+        }
+    }
+}
+
+/// The part of `img` inside `rect`, e.g. a word's bounding box from the page
+/// iterator, as a new image.
+///
+/// `rect` is clamped to the image first, so a box reaching past an edge is
+/// cut off there and one entirely outside gives an empty image.
+pub fn crop_region(img: &DynamicImage, rect: Rect) -> DynamicImage {
+    let (x, y, width, height) = clamp_region(rect.into(), img.dimensions());
+    img.crop_imm(x, y, width, height)
+}
+
+fn clamp_region(
+    (x, y, width, height): (u32, u32, u32, u32),
+    (img_width, img_height): (u32, u32),
+) -> (u32, u32, u32, u32) {
+    let x = x.min(img_width);
+    let y = y.min(img_height);
+    (x, y, width.min(img_width - x), height.min(img_height - y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn region_conversions() {
+        let rect = Rect::from_ltrb(10, 20, 110, 70);
+        assert_eq!(<(u32, u32, u32, u32)>::from(rect), (10, 20, 100, 50));
+        assert_eq!(Rect::from((10, 20, 100, 50)), rect);
+        // The part left of and above the origin is dropped.
+        let rect = Rect::from_ltrb(-5, -10, 20, 30);
+        assert_eq!(<(u32, u32, u32, u32)>::from(rect), (0, 0, 20, 30));
+        let rect = Rect::from_ltrb(-20, 0, -10, 30);
+        assert_eq!(<(u32, u32, u32, u32)>::from(rect), (0, 0, 0, 30));
+        let rect = Rect::from((u32::MAX, 0, 1, 1));
+        assert_eq!(rect.to_ltrb(), (i32::MAX, 0, i32::MAX, 1));
+        assert_eq!(
+            <(u32, u32, u32, u32)>::from(rect),
+            (i32::MAX as u32, 0, 0, 1)
+        );
+        let rect = Rect::from((10, 20, u32::MAX, u32::MAX));
+        assert_eq!(rect.to_ltrb(), (10, 20, i32::MAX, i32::MAX));
+    }
+
+    #[test]
+    fn clamped_to_image() {
+        assert_eq!(
+            clamp_region((10, 20, 100, 50), (640, 480)),
+            (10, 20, 100, 50)
+        );
+        assert_eq!(
+            clamp_region((600, 450, 100, 50), (640, 480)),
+            (600, 450, 40, 30)
+        );
+        assert_eq!(
+            clamp_region((700, 20, 100, 50), (640, 480)),
+            (640, 20, 0, 50)
+        );
+    }
+}
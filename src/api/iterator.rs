@@ -0,0 +1,422 @@
+use super::enums::poly_block_type;
+use super::{take_text, Pix, Point, Rect, TessBaseApi};
+use crate::{
+    PageIteratorLevel, PolyBlockType, TessChoiceIteratorConfidence, TessChoiceIteratorDelete,
+    TessChoiceIteratorGetUTF8Text, TessChoiceIteratorNext, TessMutableIterator, TessPageIterator,
+    TessPageIteratorBlockType, TessPageIteratorBoundingBox, TessPageIteratorDelete,
+    TessPageIteratorGetBinaryImage, TessPageIteratorGetImage, TessPageIteratorIsAtBeginningOf,
+    TessPageIteratorIsAtFinalElement, TessPageIteratorLevel, TessPageIteratorNext,
+    TessResultIterator, TessResultIteratorConfidence, TessResultIteratorDelete,
+    TessResultIteratorGetChoiceIterator, TessResultIteratorGetPageIteratorConst,
+    TessResultIteratorGetUTF8Text, TessResultIteratorNext, TessResultIteratorSymbolIsDropcap,
+    TessResultIteratorSymbolIsSubscript, TessResultIteratorSymbolIsSuperscript,
+    TessResultIteratorWordFontAttributes, TessResultIteratorWordIsFromDictionary,
+    TessResultIteratorWordIsNumeric, TessResultIteratorWordRecognitionLanguage,
+};
+use std::ffi::CStr;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+/// Iterator over the layout of the current image, as returned by
+/// `TessBaseAPIAnalyseLayout`. Borrows the API it came from.
+pub struct PageIterator<'a> {
+    raw: *mut TessPageIterator,
+    _api: PhantomData<&'a TessBaseApi>,
+}
+
+impl<'a> PageIterator<'a> {
+    /// # Safety
+    ///
+    /// `raw` must be a non-null iterator owned by the caller that stays valid
+    /// for `'a`.
+    pub(crate) unsafe fn from_raw(raw: *mut TessPageIterator) -> Self {
+        Self {
+            raw,
+            _api: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut TessPageIterator {
+        self.raw
+    }
+
+    /// Moves to the start of the next element at `level`. Returns `false` at
+    /// the end of the page.
+    pub fn next(&mut self, level: PageIteratorLevel) -> bool {
+        unsafe { TessPageIteratorNext(self.raw, level as TessPageIteratorLevel) != 0 }
+    }
+
+    /// Whether the iterator is at the start of an element at `level`.
+    ///
+    /// Together with `is_at_final_element` this recovers the block,
+    /// paragraph, line, word and symbol hierarchy while advancing at the
+    /// finest level only: when iterating with `next(RIL_WORD)`, a word for
+    /// which `is_at_beginning_of(RIL_TEXTLINE)` holds starts a new line, and
+    /// one for which `is_at_final_element(RIL_TEXTLINE, RIL_WORD)` holds ends
+    /// its line. A coarser boundary implies the finer ones, so the first word
+    /// of a block also begins a paragraph and a line.
+    pub fn is_at_beginning_of(&self, level: PageIteratorLevel) -> bool {
+        unsafe { TessPageIteratorIsAtBeginningOf(self.raw, level as TessPageIteratorLevel) != 0 }
+    }
+
+    /// Whether the current `element` is the last one of its enclosing
+    /// `level`, i.e. whether `next(element)` would move to a new element at
+    /// `level`. `element` must be finer than `level`, e.g. `RIL_WORD` within
+    /// `RIL_TEXTLINE`.
+    pub fn is_at_final_element(
+        &self,
+        level: PageIteratorLevel,
+        element: PageIteratorLevel,
+    ) -> bool {
+        unsafe {
+            TessPageIteratorIsAtFinalElement(
+                self.raw,
+                level as TessPageIteratorLevel,
+                element as TessPageIteratorLevel,
+            ) != 0
+        }
+    }
+
+    /// Bounding box of the current element at `level`, or `None` if there is
+    /// no such element.
+    pub fn bounding_box(&self, level: PageIteratorLevel) -> Option<Rect> {
+        unsafe { bounding_box(self.raw, level) }
+    }
+
+    /// Type of the current block.
+    pub fn block_type(&self) -> PolyBlockType {
+        poly_block_type(unsafe { TessPageIteratorBlockType(self.raw) })
+    }
+
+    /// Wraps `TessPageIteratorGetImage`: the current element at `level`,
+    /// cropped from `original` with `padding` pixels around it, and the
+    /// position of the crop's top left corner in `original`. Greyscale or
+    /// colour if `original` is, so it should be the image that was set.
+    ///
+    /// The padding is added to tesseract's internal bounds, so the crop
+    /// doesn't line up with `bounding_box` exactly.
+    pub fn get_image(
+        &self,
+        level: PageIteratorLevel,
+        padding: i32,
+        original: &Pix,
+    ) -> Option<(Pix, Point)> {
+        unsafe { get_image(self.raw, level, padding, original) }
+    }
+
+    /// Wraps `TessPageIteratorGetBinaryImage`: the current element at
+    /// `level` as tesseract's thresholded, 1 bit image of it.
+    pub fn get_binary_image(&self, level: PageIteratorLevel) -> Option<Pix> {
+        unsafe { get_binary_image(self.raw, level) }
+    }
+}
+
+/// # Safety
+///
+/// `raw` must be a valid page iterator, or a result iterator's view of one.
+unsafe fn bounding_box(raw: *const TessPageIterator, level: PageIteratorLevel) -> Option<Rect> {
+    let (mut left, mut top, mut right, mut bottom) = (0, 0, 0, 0);
+    let found = TessPageIteratorBoundingBox(
+        raw,
+        level as TessPageIteratorLevel,
+        &mut left,
+        &mut top,
+        &mut right,
+        &mut bottom,
+    );
+    if found != 0 {
+        Some(Rect::from_ltrb(left, top, right, bottom))
+    } else {
+        None
+    }
+}
+
+/// # Safety
+///
+/// As for `bounding_box`.
+unsafe fn get_image(
+    raw: *const TessPageIterator,
+    level: PageIteratorLevel,
+    padding: i32,
+    original: &Pix,
+) -> Option<(Pix, Point)> {
+    let (mut left, mut top) = (0, 0);
+    let image = TessPageIteratorGetImage(
+        raw,
+        level as TessPageIteratorLevel,
+        padding,
+        original.as_ptr(),
+        &mut left,
+        &mut top,
+    );
+    // The caller owns the returned image.
+    Pix::from_raw(image).map(|image| (image, Point::new(left, top)))
+}
+
+/// # Safety
+///
+/// As for `bounding_box`.
+unsafe fn get_binary_image(raw: *const TessPageIterator, level: PageIteratorLevel) -> Option<Pix> {
+    Pix::from_raw(TessPageIteratorGetBinaryImage(
+        raw,
+        level as TessPageIteratorLevel,
+    ))
+}
+
+impl Drop for PageIterator<'_> {
+    fn drop(&mut self) {
+        unsafe { TessPageIteratorDelete(self.raw) }
+    }
+}
+
+/// Iterator over recognition results, as returned by
+/// `TessBaseAPIGetIterator`. Borrows the API it came from.
+pub struct ResultIterator<'a> {
+    raw: *mut TessResultIterator,
+    _api: PhantomData<&'a TessBaseApi>,
+}
+
+impl<'a> ResultIterator<'a> {
+    /// # Safety
+    ///
+    /// `raw` must be a non-null iterator owned by the caller that stays valid
+    /// for `'a`.
+    pub(crate) unsafe fn from_raw(raw: *mut TessResultIterator) -> Self {
+        Self {
+            raw,
+            _api: PhantomData,
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut TessResultIterator {
+        self.raw
+    }
+
+    /// Moves to the start of the next element at `level`. Returns `false` at
+    /// the end of the page.
+    pub fn next(&mut self, level: PageIteratorLevel) -> bool {
+        unsafe { TessResultIteratorNext(self.raw, level as TessPageIteratorLevel) != 0 }
+    }
+
+    /// Recognised text of the current element at `level`.
+    pub fn get_utf8_text(&self, level: PageIteratorLevel) -> Option<String> {
+        unsafe {
+            take_text(TessResultIteratorGetUTF8Text(
+                self.raw,
+                level as TessPageIteratorLevel,
+            ))
+        }
+    }
+
+    /// Bounding box of the current element at `level`, or `None` if there is
+    /// no such element.
+    pub fn bounding_box(&self, level: PageIteratorLevel) -> Option<Rect> {
+        // The page iterator view belongs to this iterator.
+        unsafe { bounding_box(TessResultIteratorGetPageIteratorConst(self.raw), level) }
+    }
+
+    /// `PageIterator::get_image` for the current element.
+    pub fn get_image(
+        &self,
+        level: PageIteratorLevel,
+        padding: i32,
+        original: &Pix,
+    ) -> Option<(Pix, Point)> {
+        unsafe {
+            get_image(
+                TessResultIteratorGetPageIteratorConst(self.raw),
+                level,
+                padding,
+                original,
+            )
+        }
+    }
+
+    /// `PageIterator::get_binary_image` for the current element.
+    pub fn get_binary_image(&self, level: PageIteratorLevel) -> Option<Pix> {
+        unsafe { get_binary_image(TessResultIteratorGetPageIteratorConst(self.raw), level) }
+    }
+
+    /// Confidence of the current element at `level`, from 0 to 100.
+    ///
+    /// `None` where there is no element at `level`, e.g. past the end of the
+    /// page, for which tesseract reports a confidence of 0 as if it were one.
+    /// Negative and NaN values, which tesseract uses as sentinels, are `None`
+    /// too, and values above 100 are clamped to 100.
+    pub fn confidence(&self, level: PageIteratorLevel) -> Option<f32> {
+        self.bounding_box(level)?;
+        let confidence =
+            unsafe { TessResultIteratorConfidence(self.raw, level as TessPageIteratorLevel) };
+        if confidence.is_nan() || confidence < 0.0 {
+            None
+        } else {
+            Some(confidence.min(100.0))
+        }
+    }
+
+    /// Name of the font the current word was recognised in. Only the legacy
+    /// engine identifies fonts, so this is `None` with the LSTM engine, as
+    /// well as outside a word.
+    pub fn word_font_name(&self) -> Option<String> {
+        let (mut bold, mut italic, mut underlined) = (0, 0, 0);
+        let (mut monospace, mut serif, mut smallcaps) = (0, 0, 0);
+        let (mut point_size, mut font_id) = (0, 0);
+        // Owned by the iterator, so copied rather than freed.
+        let name = unsafe {
+            TessResultIteratorWordFontAttributes(
+                self.raw,
+                &mut bold,
+                &mut italic,
+                &mut underlined,
+                &mut monospace,
+                &mut serif,
+                &mut smallcaps,
+                &mut point_size,
+                &mut font_id,
+            )
+        };
+        if name.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(name) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
+    /// Whether the current word was found in the language's dictionary.
+    /// Only meaningful when iterating at `RIL_WORD`.
+    pub fn word_is_from_dictionary(&self) -> bool {
+        unsafe { TessResultIteratorWordIsFromDictionary(self.raw) != 0 }
+    }
+
+    /// Whether the current word was recognised as a number. Only meaningful
+    /// when iterating at `RIL_WORD`.
+    pub fn word_is_numeric(&self) -> bool {
+        unsafe { TessResultIteratorWordIsNumeric(self.raw) != 0 }
+    }
+
+    /// Language the current word was recognised with, e.g. `eng`. `None`
+    /// outside a word.
+    pub fn word_recognition_language(&self) -> Option<String> {
+        // Owned by the iterator, so copied rather than freed.
+        let language = unsafe { TessResultIteratorWordRecognitionLanguage(self.raw) };
+        if language.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(language) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
+    /// Whether the current symbol is set as a superscript, e.g. a footnote
+    /// marker. Only meaningful when iterating at `RIL_SYMBOL`.
+    pub fn symbol_is_superscript(&self) -> bool {
+        unsafe { TessResultIteratorSymbolIsSuperscript(self.raw) != 0 }
+    }
+
+    /// Whether the current symbol is set as a subscript. Only meaningful when
+    /// iterating at `RIL_SYMBOL`.
+    pub fn symbol_is_subscript(&self) -> bool {
+        unsafe { TessResultIteratorSymbolIsSubscript(self.raw) != 0 }
+    }
+
+    /// Whether the current symbol is a dropcap. Only meaningful when iterating
+    /// at `RIL_SYMBOL`.
+    pub fn symbol_is_dropcap(&self) -> bool {
+        unsafe { TessResultIteratorSymbolIsDropcap(self.raw) != 0 }
+    }
+
+    /// The characters tesseract considered for the current symbol, with
+    /// their confidences, most confident first and at most `top_n` of them.
+    /// Walks the C API's choice iterator. Only valid when iterating at
+    /// `RIL_SYMBOL`; empty outside a symbol.
+    ///
+    /// The LSTM engine only keeps alternatives when the `lstm_choice_mode`
+    /// variable is set; otherwise there is a single choice, the recognised
+    /// character.
+    pub fn symbol_choices(&self, top_n: usize) -> Vec<(String, f32)> {
+        let mut choices = Vec::new();
+        if self.bounding_box(PageIteratorLevel::RIL_SYMBOL).is_none() {
+            return choices;
+        }
+        let raw = unsafe { TessResultIteratorGetChoiceIterator(self.raw) };
+        if raw.is_null() {
+            return choices;
+        }
+        loop {
+            // Owned by the choice iterator, so copied rather than freed.
+            let text = unsafe { TessChoiceIteratorGetUTF8Text(raw) };
+            if !text.is_null() {
+                choices.push((
+                    unsafe { CStr::from_ptr(text) }
+                        .to_string_lossy()
+                        .into_owned(),
+                    unsafe { TessChoiceIteratorConfidence(raw) },
+                ));
+            }
+            if unsafe { TessChoiceIteratorNext(raw) } == 0 {
+                break;
+            }
+        }
+        unsafe { TessChoiceIteratorDelete(raw) };
+        choices.sort_by(|a, b| b.1.total_cmp(&a.1));
+        choices.truncate(top_n);
+        choices
+    }
+}
+
+impl Drop for ResultIterator<'_> {
+    fn drop(&mut self) {
+        unsafe { TessResultIteratorDelete(self.raw) }
+    }
+}
+
+/// Iterator over recognition results that is allowed to change them, as
+/// returned by `TessBaseAPIGetMutableIterator`.
+///
+/// Unlike `ResultIterator`, it borrows the API mutably, so no other iterator
+/// can observe the results while they may be changing. It derefs to
+/// `ResultIterator` for navigation and reading.
+pub struct MutableResultIterator<'a> {
+    inner: ResultIterator<'a>,
+    _api: PhantomData<&'a mut TessBaseApi>,
+}
+
+impl<'a> MutableResultIterator<'a> {
+    /// # Safety
+    ///
+    /// `raw` must be a non-null iterator owned by the caller that stays valid
+    /// for `'a`.
+    pub(crate) unsafe fn from_raw(raw: *mut TessMutableIterator) -> Self {
+        // A MutableIterator is a ResultIterator, and the C API frees it with
+        // TessResultIteratorDelete.
+        Self {
+            inner: ResultIterator::from_raw(raw as *mut TessResultIterator),
+            _api: PhantomData,
+        }
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut TessMutableIterator {
+        self.inner.raw as *mut TessMutableIterator
+    }
+}
+
+impl<'a> Deref for MutableResultIterator<'a> {
+    type Target = ResultIterator<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DerefMut for MutableResultIterator<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.inner
+    }
+}
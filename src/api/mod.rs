@@ -0,0 +1,1554 @@
+//! Safe wrappers around the tesseract C API.
+//!
+//! Only available with the `api` feature.
+
+mod build_info;
+mod config;
+mod enums;
+mod error;
+mod geometry;
+#[cfg(feature = "image-file")]
+mod image_file;
+#[cfg(feature = "image-interop")]
+mod image_interop;
+mod iterator;
+mod multi_lang;
+mod page_stream;
+mod pix;
+mod pool;
+mod renderer;
+mod tsv;
+
+pub use self::build_info::BuildInfo;
+pub use self::config::TessConfig;
+pub use self::enums::{ImageThresholdingMethod, PixelFormat, Script};
+pub use self::error::{Result, TesseractError};
+pub use self::geometry::{Point, Rect};
+#[cfg(feature = "image-file")]
+pub use self::image_file::{pix_bytes_per_line, ImageFormat};
+#[cfg(feature = "image-interop")]
+pub use self::image_interop::crop_region;
+pub use self::iterator::{MutableResultIterator, PageIterator, ResultIterator};
+pub use self::multi_lang::MultiLangApi;
+pub use self::page_stream::PageTextStream;
+pub use self::pix::Pix;
+pub use self::pool::{TessPool, TessPoolConfig};
+pub use self::renderer::{OutputFormat, Renderer};
+pub use self::tsv::{parse_tsv, TsvRow};
+
+use crate::util::{delete_int_array, delete_text_array};
+#[cfg(tesseract_5)]
+use crate::TessBaseAPIInit5;
+use crate::{
+    OcrEngineMode, PageIteratorLevel, PageSegMode, PolyBlockType, TessBaseAPI,
+    TessBaseAPIAllWordConfidences, TessBaseAPIAnalyseLayout, TessBaseAPIClear,
+    TessBaseAPIClearAdaptiveClassifier, TessBaseAPICreate, TessBaseAPIDelete,
+    TessBaseAPIDetectOrientationScript, TessBaseAPIGetConnectedComponents, TessBaseAPIGetDatapath,
+    TessBaseAPIGetHOCRText, TessBaseAPIGetInitLanguagesAsString, TessBaseAPIGetIntVariable,
+    TessBaseAPIGetIterator, TessBaseAPIGetLoadedLanguagesAsVector, TessBaseAPIGetMutableIterator,
+    TessBaseAPIGetOpenCLDevice, TessBaseAPIGetPageSegMode, TessBaseAPIGetSourceYResolution,
+    TessBaseAPIGetStringVariable, TessBaseAPIGetTextlines1, TessBaseAPIGetTsvText,
+    TessBaseAPIGetUTF8Text, TessBaseAPIInit2, TessBaseAPIInit3, TessBaseAPIOem,
+    TessBaseAPIPrintVariablesToFile, TessBaseAPIProcessPages, TessBaseAPIRecognize,
+    TessBaseAPISetImage, TessBaseAPISetImage2, TessBaseAPISetMinOrientationMargin,
+    TessBaseAPISetOutputName, TessBaseAPISetPageSegMode, TessBaseAPISetSourceResolution,
+    TessBaseAPISetVariable, TessDeleteText, TessMonitor, TessMonitorCreate, TessMonitorDelete,
+    TessMonitorSetCancelFunc, TessMonitorSetCancelThis, TessOcrEngineMode, TessPageSegMode,
+};
+use leptonica_sys::{
+    boxaDestroy, boxaGetBoxGeometry, boxaGetCount, pixaDestroy, pixaGetCount, pixaGetPix, Boxa,
+    Pixa, L_CLONE,
+};
+use std::cell::Cell;
+use std::collections::hash_map::RandomState;
+use std::convert::TryFrom;
+use std::env;
+use std::ffi::{CStr, CString};
+use std::fs::{self, File};
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, Write};
+use std::os::raw::{c_char, c_float, c_int, c_void};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Owned handle to a `TessBaseAPI`, deleted on drop.
+pub struct TessBaseApi {
+    raw: *mut TessBaseAPI,
+    // Whether recognition ran since the image was last set or cleared.
+    recognized: Cell<bool>,
+    // Whether the current image has a known resolution, either its own or
+    // one given to set_source_resolution.
+    resolution_set: Cell<bool>,
+    // Width and height of the current image, as it was set.
+    image_size: Cell<Option<(i32, i32)>>,
+}
+
+/// A recognised word, as collected by `recognize_structured`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Word {
+    pub text: String,
+    /// From 0 to 100.
+    pub confidence: f32,
+    pub bbox: Rect,
+    /// Font name, only known with the legacy engine.
+    pub font: Option<String>,
+}
+
+/// A text line found by `textlines_with_ids`, with the block and paragraph
+/// it belongs to.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextLine {
+    pub bbox: Rect,
+    /// Index of the block, counted from 0 over the page.
+    pub block_id: i32,
+    /// Index of the paragraph within its block, counted from 0 in each block.
+    /// Lines belong to the same paragraph when both ids match.
+    pub paragraph_id: i32,
+}
+
+// Each handle owns a separate tesseract instance with nothing tied to the
+// thread that created it, so it may move between threads. It isn't Sync:
+// tesseract's methods are not safe to call concurrently on one instance.
+unsafe impl Send for TessBaseApi {}
+
+/// Result of orientation and script detection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrientationScript {
+    /// Rotation, in degrees, needed to bring the page upright.
+    pub orient_deg: i32,
+    pub orient_conf: f32,
+    /// Script name as reported by tesseract, e.g. `"Latin"`.
+    pub script_name: String,
+    /// `script_name` parsed, or `None` if tesseract reported no script.
+    pub script: Option<Script>,
+    pub script_conf: f32,
+}
+
+impl TessBaseApi {
+    /// Wraps `TessBaseAPICreate`. Fails with `CreateError` if it returns
+    /// null.
+    pub fn new() -> Result<Self> {
+        let raw = unsafe { TessBaseAPICreate() };
+        if raw.is_null() {
+            return Err(TesseractError::CreateError);
+        }
+        Ok(Self {
+            raw,
+            recognized: Cell::new(false),
+            resolution_set: Cell::new(false),
+            image_size: Cell::new(None),
+        })
+    }
+
+    /// Raw handle, for calling C API functions that have no wrapper yet.
+    pub fn as_ptr(&self) -> *mut TessBaseAPI {
+        self.raw
+    }
+
+    /// Wraps `TessBaseAPIInit3`. A `datapath` of `None` lets tesseract find
+    /// its data through `TESSDATA_PREFIX`.
+    ///
+    /// Fails with `MissingLanguageData` if the `.traineddata` file of a
+    /// requested language isn't in the datapath.
+    pub fn init(&mut self, datapath: Option<&str>, language: &str) -> Result<()> {
+        let c_datapath = datapath.map(CString::new).transpose()?;
+        let c_language = CString::new(language)?;
+        let ret = unsafe {
+            TessBaseAPIInit3(
+                self.raw,
+                c_datapath.as_ref().map_or(ptr::null(), |d| d.as_ptr()),
+                c_language.as_ptr(),
+            )
+        };
+        self.recognized.set(false);
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(init_error(datapath, language))
+        }
+    }
+
+    /// Wraps `TessBaseAPIInit2`: `init` with the engine mode `oem`.
+    ///
+    /// Fails with `MissingLanguageData` if the `.traineddata` file of a
+    /// requested language isn't in the datapath, and with `InitError` for
+    /// other failures, such as a datapath that doesn't exist or an engine
+    /// mode the language data has no model for.
+    pub fn init_with_oem(
+        &mut self,
+        datapath: Option<&str>,
+        language: &str,
+        oem: OcrEngineMode,
+    ) -> Result<()> {
+        let c_datapath = datapath.map(CString::new).transpose()?;
+        let c_language = CString::new(language)?;
+        let ret = unsafe {
+            TessBaseAPIInit2(
+                self.raw,
+                c_datapath.as_ref().map_or(ptr::null(), |d| d.as_ptr()),
+                c_language.as_ptr(),
+                oem as TessOcrEngineMode,
+            )
+        };
+        self.recognized.set(false);
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(init_error(datapath, language))
+        }
+    }
+
+    /// Re-initialises the existing handle with `init_with_oem`, e.g. to
+    /// switch engine mode. Resets `has_recognized`.
+    ///
+    /// This reloads the language model, which is as expensive as the first
+    /// `init`; prefer keeping one API per engine mode when switching often.
+    pub fn reinit(
+        &mut self,
+        datapath: Option<&str>,
+        language: &str,
+        oem: OcrEngineMode,
+    ) -> Result<()> {
+        self.init_with_oem(datapath, language, oem)
+    }
+
+    /// The engine recognition uses: the `tessedit_ocr_engine_mode` variable,
+    /// which `init` resolves `OEM_DEFAULT` to the mode the language data
+    /// supports. `None` before `init`.
+    pub fn engine_mode(&self) -> Option<OcrEngineMode> {
+        let mode = self
+            .get_int_variable("tessedit_ocr_engine_mode")
+            .ok()
+            .flatten()?;
+        OcrEngineMode::try_from(mode).ok()
+    }
+
+    /// Switches the engine the next recognition uses to `oem`, without
+    /// reloading the language data when it can. Resets `has_recognized`.
+    ///
+    /// Which engines can run is fixed when the language data is loaded:
+    /// only an API initialised with `OEM_TESSERACT_LSTM_COMBINED` has both
+    /// the legacy and the LSTM models in memory. For such an API with a
+    /// single language, switching between `OEM_TESSERACT_ONLY`,
+    /// `OEM_LSTM_ONLY` and `OEM_TESSERACT_LSTM_COMBINED` only sets the
+    /// `tessedit_ocr_engine_mode` variable, which tesseract reads when it
+    /// recognises. Anything else re-initialises like `reinit`, with the same
+    /// datapath and languages, which is as expensive as the first `init` and
+    /// resets the variables set since: switching to `OEM_DEFAULT`, from an
+    /// API initialised with another mode, or with several languages, whose
+    /// other languages keep their own copy of the variable.
+    ///
+    /// Fails with `InitError` before `init`, or if the language data has no
+    /// model for `oem`, e.g. `OEM_TESSERACT_ONLY` with the LSTM-only models
+    /// of `tessdata_fast`.
+    pub fn set_engine_mode(&mut self, oem: OcrEngineMode) -> Result<()> {
+        let languages = self.init_languages().ok_or(TesseractError::InitError)?;
+        let loaded_both = unsafe { TessBaseAPIOem(self.raw) }
+            == OcrEngineMode::OEM_TESSERACT_LSTM_COMBINED as TessOcrEngineMode;
+        if loaded_both && !languages.contains('+') && oem != OcrEngineMode::OEM_DEFAULT {
+            self.recognized.set(false);
+            return self.set_variable("tessedit_ocr_engine_mode", &(oem as i32).to_string());
+        }
+        let datapath = self.datapath();
+        self.init_with_oem(datapath.as_deref(), &languages, oem)
+    }
+
+    /// Initialises with a null datapath, so tesseract locates its data through
+    /// `TESSDATA_PREFIX` (or its compiled-in default). Fails with
+    /// `TessdataNotFound` rather than a bare `InitError`.
+    pub fn init_default(&mut self, language: &str) -> Result<()> {
+        self.init(None, language).map_err(|e| match e {
+            TesseractError::InitError => TesseractError::TessdataNotFound {
+                tessdata_prefix: env::var_os("TESSDATA_PREFIX").map(Into::into),
+            },
+            e => e,
+        })
+    }
+
+    /// Initialises with every model in the tessdata directory `dir`, e.g.
+    /// with `eng+fra` for a directory holding `eng.traineddata` and
+    /// `fra.traineddata`, or only with the models `only` lists. Languages are
+    /// joined in alphabetical order, so the first one is the primary.
+    ///
+    /// `osd.traineddata` holds no recognition model and is left out unless
+    /// `only` names it. Fails with `NoLanguageData` if no model is left, and
+    /// with `MissingLanguageData` if `only` names one `dir` doesn't have.
+    pub fn init_from_tessdata_dir(&mut self, dir: &Path, only: Option<&[&str]>) -> Result<()> {
+        let mut languages: Vec<String> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+                if path.extension()? != "traineddata" {
+                    return None;
+                }
+                Some(path.file_stem()?.to_str()?.to_string())
+            })
+            .collect();
+        match only {
+            Some(only) => {
+                if let Some(missing) = only
+                    .iter()
+                    .find(|lang| !languages.iter().any(|l| l == *lang))
+                {
+                    return Err(TesseractError::MissingLanguageData {
+                        lang: missing.to_string(),
+                        datapath: dir.to_path_buf(),
+                    });
+                }
+                languages.retain(|lang| only.contains(&lang.as_str()));
+            }
+            None => languages.retain(|lang| lang != "osd"),
+        }
+        if languages.is_empty() {
+            return Err(TesseractError::NoLanguageData {
+                dir: dir.to_path_buf(),
+            });
+        }
+        languages.sort();
+        self.init(Some(&dir.to_string_lossy()), &languages.join("+"))
+    }
+
+    /// Wraps `TessBaseAPIInit5`, loading the `.traineddata` for `language`
+    /// from `data` instead of a datapath, e.g. a model embedded with
+    /// `include_bytes!`. Only available when linking tesseract 5.0 or newer.
+    ///
+    /// Fails with `InitError` if `data` is empty (tesseract would then fall
+    /// back to reading from disk), too large for a C `int`, or not a valid
+    /// model.
+    #[cfg(tesseract_5)]
+    pub fn init_from_memory(
+        &mut self,
+        data: &[u8],
+        language: &str,
+        oem: OcrEngineMode,
+    ) -> Result<()> {
+        if data.is_empty() || data.len() > c_int::MAX as usize {
+            return Err(TesseractError::InitError);
+        }
+        let c_language = CString::new(language)?;
+        let ret = unsafe {
+            TessBaseAPIInit5(
+                self.raw,
+                data.as_ptr() as *const c_char,
+                data.len() as c_int,
+                c_language.as_ptr(),
+                oem as TessOcrEngineMode,
+                ptr::null_mut(),
+                0,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                0,
+                0,
+            )
+        };
+        self.recognized.set(false);
+        if ret == 0 {
+            Ok(())
+        } else {
+            Err(TesseractError::InitError)
+        }
+    }
+
+    /// Wraps `TessBaseAPIGetDatapath`: the tessdata directory in use after
+    /// `init`.
+    pub fn datapath(&self) -> Option<String> {
+        let datapath = unsafe { TessBaseAPIGetDatapath(self.raw) };
+        if datapath.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(datapath) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
+    /// Basenames of the files in `<datapath>/configs`, sorted, i.e. the names
+    /// `TessConfig::config` accepts. Empty if the API isn't initialised or
+    /// the directory doesn't exist.
+    pub fn available_configs(&self) -> Vec<String> {
+        let dir = match self.datapath() {
+            Some(datapath) if !datapath.is_empty() => PathBuf::from(datapath).join("configs"),
+            _ => return Vec::new(),
+        };
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+        let mut configs: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| matches!(entry.file_type(), Ok(t) if t.is_file()))
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .collect();
+        configs.sort();
+        configs
+    }
+
+    /// Wraps `TessBaseAPIGetInitLanguagesAsString`: the language string as
+    /// passed to `init`, e.g. `"eng+osd"`. `None` before `init`.
+    pub fn init_languages(&self) -> Option<String> {
+        let languages = unsafe { TessBaseAPIGetInitLanguagesAsString(self.raw) };
+        if languages.is_null() {
+            return None;
+        }
+        let languages = unsafe { CStr::from_ptr(languages) }.to_string_lossy();
+        if languages.is_empty() {
+            None
+        } else {
+            Some(languages.into_owned())
+        }
+    }
+
+    /// Wraps `TessBaseAPIGetLoadedLanguagesAsVector`: every language actually
+    /// loaded, which besides the requested ones includes those a model pulls
+    /// in itself (e.g. through `tessedit_load_sublangs`).
+    pub fn loaded_languages(&self) -> Vec<String> {
+        unsafe { take_text_array(TessBaseAPIGetLoadedLanguagesAsVector(self.raw)) }
+    }
+
+    /// The loaded languages that weren't requested in `init_languages`.
+    pub fn extra_loaded_languages(&self) -> Vec<String> {
+        let requested = self.init_languages().unwrap_or_default();
+        let requested: Vec<&str> = requested.split('+').collect();
+        self.loaded_languages()
+            .into_iter()
+            .filter(|language| !requested.contains(&language.as_str()))
+            .collect()
+    }
+
+    /// Wraps `TessBaseAPISetImage`.
+    ///
+    /// Tesseract copies the pixels into its own image during this call, so
+    /// `data` only needs to live until it returns. A memory-mapped buffer can
+    /// be passed directly and unmapped straight afterwards, and a decoder's
+    /// buffer can be reused or freed; there is no need to keep it alive
+    /// alongside the API.
+    pub fn set_image(
+        &mut self,
+        data: &[u8],
+        width: i32,
+        height: i32,
+        bytes_per_pixel: i32,
+        bytes_per_line: i32,
+    ) -> Result<()> {
+        if width < 0 || height < 0 || bytes_per_pixel < 0 || bytes_per_line < 0 {
+            return Err(TesseractError::InvalidImage(
+                "dimensions must not be negative".to_string(),
+            ));
+        }
+        if width == 0 || height == 0 {
+            return Err(TesseractError::InvalidImage(format!(
+                "{}x{} image is empty",
+                width, height
+            )));
+        }
+        // A bytes_per_pixel of 0 means one bit per pixel.
+        let row_bytes = if bytes_per_pixel == 0 {
+            Some((width - 1) / 8 + 1)
+        } else {
+            width.checked_mul(bytes_per_pixel)
+        };
+        match row_bytes {
+            Some(row_bytes) if row_bytes <= bytes_per_line => {}
+            _ => {
+                return Err(TesseractError::InvalidImage(format!(
+                    "lines of {} bytes are too short for {} pixels of {} bytes",
+                    bytes_per_line, width, bytes_per_pixel
+                )))
+            }
+        }
+        match (bytes_per_line as usize).checked_mul(height as usize) {
+            Some(size) if size <= data.len() => {}
+            _ => {
+                return Err(TesseractError::InvalidImage(format!(
+                    "{} bytes is too small for {} lines of {} bytes",
+                    data.len(),
+                    height,
+                    bytes_per_line
+                )))
+            }
+        }
+        unsafe {
+            TessBaseAPISetImage(
+                self.raw,
+                data.as_ptr(),
+                width,
+                height,
+                bytes_per_pixel,
+                bytes_per_line,
+            )
+        };
+        self.recognized.set(false);
+        self.resolution_set.set(false);
+        self.image_size.set(Some((width, height)));
+        Ok(())
+    }
+
+    /// `set_image` with `bytes_per_pixel` and `bytes_per_line` derived from
+    /// `format`, for lines packed without padding. Images whose lines are
+    /// padded (e.g. to a multiple of 4 bytes) need `set_image`.
+    pub fn set_image_fmt(
+        &mut self,
+        data: &[u8],
+        width: i32,
+        height: i32,
+        format: PixelFormat,
+    ) -> Result<()> {
+        let bytes_per_line = format.bytes_per_line(width).ok_or_else(|| {
+            TesseractError::InvalidImage(format!("{} pixels wide is too wide", width))
+        })?;
+        self.set_image(
+            data,
+            width,
+            height,
+            format.bytes_per_pixel(),
+            bytes_per_line,
+        )
+    }
+
+    /// Wraps `TessBaseAPISetImage2`. Tesseract keeps its own reference to the
+    /// image, so `pix` may be dropped afterwards.
+    ///
+    /// The vertical resolution `pix` carries, e.g. from a PNG's `pHYs` chunk
+    /// or a TIFF's tags, becomes the source resolution when it's known (above
+    /// 0), so tesseract doesn't estimate it. `set_image_pix_with_resolution`
+    /// gives one explicitly instead.
+    pub fn set_image_pix(&mut self, pix: &Pix) {
+        self.set_image_pix_with_resolution(pix, pix.y_resolution());
+    }
+
+    /// `set_image_pix` with `ppi` as the source resolution, whatever `pix`
+    /// carries. A `ppi` of 0 or less leaves tesseract to estimate it.
+    pub fn set_image_pix_with_resolution(&mut self, pix: &Pix, ppi: i32) {
+        unsafe { TessBaseAPISetImage2(self.raw, pix.as_ptr()) };
+        self.recognized.set(false);
+        self.resolution_set.set(false);
+        self.image_size.set(Some((pix.width(), pix.height())));
+        if ppi > 0 {
+            self.set_source_resolution(ppi);
+        }
+    }
+
+    /// Wraps `TessBaseAPIGetSourceYResolution`: the vertical resolution, in
+    /// pixels per inch, of the image set last. `None` if it is unknown.
+    ///
+    /// Tesseract 4 dereferences the image without checking for one, so call
+    /// this after setting an image.
+    pub fn source_y_resolution(&self) -> Option<i32> {
+        let ppi = unsafe { TessBaseAPIGetSourceYResolution(self.raw) };
+        if ppi > 0 {
+            Some(ppi)
+        } else {
+            None
+        }
+    }
+
+    /// Width and height of the image set last, as given to `set_image` or
+    /// read from the `Pix` passed to `set_image_pix`. Tracked on the Rust
+    /// side, as tesseract has no getter for them.
+    ///
+    /// `None` before an image is set, after `clear`, and after
+    /// `process_pages` or `ocr_to_file`, which leave tesseract on their last
+    /// page.
+    pub fn image_size(&self) -> Option<(i32, i32)> {
+        self.image_size.get()
+    }
+
+    /// Wraps `TessBaseAPISetSourceResolution`: the resolution, in pixels per
+    /// inch, of the image set last. Setting an image resets it, so call this
+    /// afterwards.
+    pub fn set_source_resolution(&self, ppi: i32) {
+        unsafe { TessBaseAPISetSourceResolution(self.raw, ppi) };
+        self.resolution_set.set(ppi > 0);
+    }
+
+    /// Sets `user_defined_dpi` to 70, the resolution tesseract falls back to
+    /// itself, so that images without a credible resolution no longer print
+    /// `Warning: Invalid resolution 0 dpi. Using 70 instead.` or the
+    /// `Estimating resolution` message.
+    ///
+    /// This is cosmetic: recognition at 70 dpi is about what tesseract's own
+    /// estimate gives. It applies to every later image, though, and overrides
+    /// their resolution too, even one given to `set_source_resolution`.
+    /// Setting `user_defined_dpi` back to 0 restores both. `hocr_validated`
+    /// still treats the images as having no resolution.
+    pub fn suppress_resolution_warning(&self) -> Result<()> {
+        self.set_variable("user_defined_dpi", "70")
+    }
+
+    /// Wraps `TessBaseAPIClear`: frees the image and recognition results but
+    /// keeps the loaded language data.
+    pub fn clear(&mut self) {
+        unsafe { TessBaseAPIClear(self.raw) };
+        self.recognized.set(false);
+        self.resolution_set.set(false);
+        self.image_size.set(None);
+    }
+
+    /// Wraps `TessBaseAPIClearAdaptiveClassifier`: forgets what the legacy
+    /// engine's adaptive classifier learnt from the pages recognised so far,
+    /// which `clear` keeps. The LSTM engine doesn't adapt.
+    pub fn clear_adaptive_classifier(&mut self) {
+        unsafe { TessBaseAPIClearAdaptiveClassifier(self.raw) };
+    }
+
+    /// Whether `recognize` or a text getter has run since the image was last
+    /// set or the API cleared. Tracked on the Rust side only.
+    pub fn has_recognized(&self) -> bool {
+        self.recognized.get()
+    }
+
+    /// Wraps `TessBaseAPIGetOpenCLDevice`: an opaque handle to the OpenCL
+    /// device tesseract selected, for diagnostics.
+    ///
+    /// Always `None` when tesseract was built without OpenCL.
+    pub fn opencl_device(&self) -> Option<usize> {
+        let mut device: *mut c_void = ptr::null_mut();
+        let size = unsafe { TessBaseAPIGetOpenCLDevice(self.raw, &mut device) };
+        if size == 0 || device.is_null() {
+            None
+        } else {
+            Some(device as usize)
+        }
+    }
+
+    /// Wraps `TessBaseAPISetVariable`. Fails if tesseract has no variable
+    /// called `name` or can't parse `value`.
+    pub fn set_variable(&self, name: &str, value: &str) -> Result<()> {
+        let c_name = CString::new(name)?;
+        let c_value = CString::new(value)?;
+        if unsafe { TessBaseAPISetVariable(self.raw, c_name.as_ptr(), c_value.as_ptr()) } != 0 {
+            Ok(())
+        } else {
+            Err(TesseractError::SetVariableError {
+                name: name.to_string(),
+                value: value.to_string(),
+            })
+        }
+    }
+
+    /// Wraps `TessBaseAPIGetIntVariable`: the value of the integer variable
+    /// `name`. `None` before `init`, and for unknown or non-integer
+    /// variables. Fails with `NulError` if `name` contains a NUL byte.
+    pub fn get_int_variable(&self, name: &str) -> Result<Option<i32>> {
+        let c_name = CString::new(name)?;
+        // Like TessBaseAPIGetStringVariable, this dereferences the engine
+        // `init` creates.
+        if self.init_languages().is_none() {
+            return Ok(None);
+        }
+        let mut value: c_int = 0;
+        if unsafe { TessBaseAPIGetIntVariable(self.raw, c_name.as_ptr(), &mut value) } == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(value))
+        }
+    }
+
+    // TessBaseAPIGetStringVariable dereferences the engine `init` creates, so
+    // this is `None` before `init`, as well as for unknown variables.
+    fn string_variable(&self, name: &str) -> Option<String> {
+        self.init_languages()?;
+        let c_name = CString::new(name).ok()?;
+        let value = unsafe { TessBaseAPIGetStringVariable(self.raw, c_name.as_ptr()) };
+        if value.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(value) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
+    /// Wraps `TessBaseAPISetOutputName`: the base name of the debugging and
+    /// training files tesseract writes, such as the `.tr` files of
+    /// `tessedit_train_from_boxes`.
+    ///
+    /// Renderers don't read it. They write to the base name they were
+    /// created with (`ocr_to_file`'s `output_base`) plus their `Extension`,
+    /// `OutputFormat::extension`, and their `Title` is the `document_title`
+    /// variable, which `ocr_to_file` fills in.
+    pub fn set_output_name(&self, name: &str) -> Result<()> {
+        let c_name = CString::new(name)?;
+        unsafe { TessBaseAPISetOutputName(self.raw, c_name.as_ptr()) };
+        Ok(())
+    }
+
+    /// Restricts recognition to `chars` by setting `tessedit_char_whitelist`.
+    ///
+    /// The LSTM engine ignores the whitelist in tesseract 4.0 and honours it
+    /// from 4.1 on; the legacy engine (`OEM_TESSERACT_ONLY`) always does. An
+    /// empty string lifts the restriction, as `clear_char_whitelist` does.
+    pub fn set_char_whitelist(&self, chars: &str) -> Result<()> {
+        self.set_variable("tessedit_char_whitelist", chars)
+    }
+
+    /// Excludes `chars` from recognition by setting `tessedit_char_blacklist`.
+    /// Subject to the same engine mode caveats as `set_char_whitelist`.
+    pub fn set_char_blacklist(&self, chars: &str) -> Result<()> {
+        self.set_variable("tessedit_char_blacklist", chars)
+    }
+
+    /// Lifts a restriction set with `set_char_whitelist`.
+    pub fn clear_char_whitelist(&self) -> Result<()> {
+        self.set_char_whitelist("")
+    }
+
+    /// Sends tesseract's diagnostics, such as `Estimating resolution as ...`
+    /// or warnings about missing data, to `path` instead of stderr, by
+    /// setting the `debug_file` variable. Setting that variable back to an
+    /// empty string returns to stderr, closing the file at the next message.
+    ///
+    /// Only messages tesseract prints through its own `tprintf` are
+    /// redirected. Leptonica's messages (`Error in pixRead: ...`) and the few
+    /// tesseract writes to stderr directly still go there.
+    ///
+    /// `debug_file` is a global variable: this redirects the diagnostics of
+    /// every `TessBaseApi` in the process. The file is created, or truncated,
+    /// when the first message arrives, and is kept open, and buffered, until
+    /// `debug_file` is emptied, so a different path set in between is
+    /// ignored.
+    pub fn set_debug_file(&self, path: &Path) -> Result<()> {
+        let c_path = path_to_cstring(path)?;
+        let ok = unsafe {
+            TessBaseAPISetVariable(self.raw, b"debug_file\0".as_ptr().cast(), c_path.as_ptr())
+        };
+        if ok != 0 {
+            Ok(())
+        } else {
+            Err(TesseractError::SetVariableError {
+                name: "debug_file".to_string(),
+                value: path.to_string_lossy().into_owned(),
+            })
+        }
+    }
+
+    /// Discards tesseract's diagnostics with `set_debug_file`, which has the
+    /// same limitations. The null device is `/dev/null`, or `nul` on Windows.
+    pub fn silence_diagnostics(&self) -> Result<()> {
+        let null_device = if cfg!(windows) { "nul" } else { "/dev/null" };
+        self.set_debug_file(Path::new(null_device))
+    }
+
+    /// Sets `thresholding_method`. Requires tesseract 5.0 or newer.
+    pub fn set_thresholding_method(&self, method: ImageThresholdingMethod) -> Result<()> {
+        self.set_variable("thresholding_method", &(method as i32).to_string())
+    }
+
+    /// Wraps `TessBaseAPISetPageSegMode`, and sets the
+    /// `tessedit_pageseg_mode` variable to the same mode.
+    ///
+    /// `SetPageSegMode` stores the mode in that variable, but some tesseract
+    /// versions have been seen reporting different modes through
+    /// `page_seg_mode` and the variable after a re-initialisation. Setting
+    /// both keeps them consistent whichever one tesseract reads.
+    pub fn set_page_seg_mode(&self, mode: PageSegMode) {
+        unsafe { set_page_seg_mode(self.raw, mode) }
+    }
+
+    /// Wraps `TessBaseAPIGetPageSegMode`. `PSM_SINGLE_BLOCK`, tesseract's
+    /// default, before `init`.
+    pub fn page_seg_mode(&self) -> PageSegMode {
+        let mode = unsafe { TessBaseAPIGetPageSegMode(self.raw) };
+        PageSegMode::try_from(mode as i32).unwrap_or(PageSegMode::PSM_SINGLE_BLOCK)
+    }
+
+    /// Recognises an image with `set_image_fmt` and returns its text, using
+    /// `psm` for this call only.
+    ///
+    /// The page segmentation mode set before is saved and restored
+    /// afterwards, whether recognition succeeds or fails, so other calls on
+    /// the API keep theirs.
+    pub fn ocr_image_with_psm(
+        &mut self,
+        data: &[u8],
+        width: i32,
+        height: i32,
+        format: PixelFormat,
+        psm: PageSegMode,
+    ) -> Result<String> {
+        let _restore = RestorePageSegMode {
+            raw: self.raw,
+            previous: self.page_seg_mode(),
+        };
+        self.set_page_seg_mode(psm);
+        self.set_image_fmt(data, width, height, format)?;
+        self.get_utf8_text()
+    }
+
+    /// Wraps `TessBaseAPIRecognize` without a progress monitor.
+    ///
+    /// Call this once before extracting several output formats; the `get_*`
+    /// text methods reuse the result. A getter called without recognising
+    /// first still works, as tesseract then recognises the image itself.
+    pub fn recognize(&mut self) -> Result<()> {
+        if unsafe { TessBaseAPIRecognize(self.raw, ptr::null_mut()) } == 0 {
+            self.recognized.set(true);
+            Ok(())
+        } else {
+            Err(TesseractError::RecognizeError)
+        }
+    }
+
+    /// Like `recognize`, but stops early once `cancel` is set, e.g. from
+    /// another thread, and returns `TesseractError::Cancelled`.
+    ///
+    /// Tesseract checks the flag between words, so recognition stops soon
+    /// after it is set, not at once. If it finishes before noticing, the
+    /// results are kept and this returns `Ok`. After a cancellation the
+    /// results are incomplete; call `recognize` again for all of them.
+    pub fn recognize_cancellable(&mut self, cancel: Arc<AtomicBool>) -> Result<()> {
+        let monitor = Monitor::new()?;
+        unsafe {
+            TessMonitorSetCancelFunc(monitor.raw, Some(cancel_requested));
+            TessMonitorSetCancelThis(monitor.raw, Arc::as_ptr(&cancel) as *mut c_void);
+        }
+        // `cancel` outlives the call, and `monitor` is deleted on return.
+        if unsafe { TessBaseAPIRecognize(self.raw, monitor.raw) } == 0 {
+            self.recognized.set(true);
+            Ok(())
+        } else if cancel.load(Ordering::SeqCst) {
+            Err(TesseractError::Cancelled)
+        } else {
+            Err(TesseractError::RecognizeError)
+        }
+    }
+
+    /// Wraps `TessBaseAPIGetUTF8Text`.
+    pub fn get_utf8_text(&mut self) -> Result<String> {
+        let text = unsafe { take_text(TessBaseAPIGetUTF8Text(self.raw)) }
+            .ok_or(TesseractError::GetTextError)?;
+        self.recognized.set(true);
+        Ok(text)
+    }
+
+    /// Recognises `pix` and returns its text: `set_image_pix`, `recognize`
+    /// and `get_utf8_text` in one call, with the language data loaded by
+    /// `init`.
+    ///
+    /// `pix` only has to live for the call, which the borrow ensures.
+    /// Tesseract keeps its own reference, so the results can still be read,
+    /// e.g. through `get_iterator`, after `pix` is dropped.
+    pub fn ocr_pix(&mut self, pix: &Pix) -> Result<String> {
+        self.ocr_pix_with_resolution(pix, pix.y_resolution())
+    }
+
+    /// `ocr_pix` with `ppi` as the source resolution, as with
+    /// `set_image_pix_with_resolution`.
+    pub fn ocr_pix_with_resolution(&mut self, pix: &Pix, ppi: i32) -> Result<String> {
+        self.set_image_pix_with_resolution(pix, ppi);
+        self.recognize()?;
+        self.get_utf8_text()
+    }
+
+    /// Every recognised word with its confidence, bounding box and font, in
+    /// reading order. Recognises the image first if that hasn't happened yet.
+    ///
+    /// With the `serde` feature the words can be serialised directly, e.g.
+    /// with `serde_json::to_string(&api.recognize_structured()?)`.
+    pub fn recognize_structured(&mut self) -> Result<Vec<Word>> {
+        self.ensure_recognized()?;
+        let mut words = Vec::new();
+        let mut it = match self.get_iterator() {
+            Some(it) => it,
+            None => return Ok(words),
+        };
+        loop {
+            if let (Some(text), Some(bbox)) = (
+                it.get_utf8_text(PageIteratorLevel::RIL_WORD),
+                it.bounding_box(PageIteratorLevel::RIL_WORD),
+            ) {
+                words.push(Word {
+                    text,
+                    confidence: it.confidence(PageIteratorLevel::RIL_WORD).unwrap_or(0.0),
+                    bbox,
+                    font: it.word_font_name(),
+                });
+            }
+            if !it.next(PageIteratorLevel::RIL_WORD) {
+                break;
+            }
+        }
+        Ok(words)
+    }
+
+    /// Every recognised word with its confidence (0 to 100), in reading
+    /// order. Recognises the image first if that hasn't happened yet.
+    ///
+    /// Each confidence is read from the result iterator while it is on the
+    /// word, so the pairing always holds. Zipping the words of
+    /// `get_utf8_text` with `all_word_confidences` instead goes wrong as soon
+    /// as the two disagree on what a word is: the text may split or join words
+    /// differently from tesseract's own segmentation, e.g. around hyphenated
+    /// line ends, and every pair after the first mismatch is then off.
+    pub fn words_with_confidences(&mut self) -> Result<Vec<(String, i32)>> {
+        self.ensure_recognized()?;
+        let mut words = Vec::new();
+        let mut it = match self.get_iterator() {
+            Some(it) => it,
+            None => return Ok(words),
+        };
+        loop {
+            if let Some(text) = it.get_utf8_text(PageIteratorLevel::RIL_WORD) {
+                let confidence = it.confidence(PageIteratorLevel::RIL_WORD).unwrap_or(0.0);
+                words.push((text, confidence as i32));
+            }
+            if !it.next(PageIteratorLevel::RIL_WORD) {
+                break;
+            }
+        }
+        Ok(words)
+    }
+
+    /// Wraps `TessBaseAPIAllWordConfidences`: the confidence (0 to 100) of
+    /// every word, in reading order, without the words. See
+    /// `words_with_confidences` for the words alongside. Recognises the image
+    /// first if that hasn't happened yet.
+    pub fn all_word_confidences(&mut self) -> Result<Vec<i32>> {
+        self.ensure_recognized()?;
+        let confidences = unsafe { TessBaseAPIAllWordConfidences(self.raw) };
+        if confidences.is_null() {
+            return Err(TesseractError::GetTextError);
+        }
+        // Terminated by -1.
+        let mut result = Vec::new();
+        unsafe {
+            let mut next = confidences;
+            while *next != -1 {
+                result.push(*next);
+                next = next.add(1);
+            }
+            delete_int_array(confidences);
+        }
+        Ok(result)
+    }
+
+    // What the `get_*` text functions do implicitly, for the functions that
+    // read the results through other means.
+    fn ensure_recognized(&mut self) -> Result<()> {
+        if !self.has_recognized() {
+            if unsafe { TessBaseAPIRecognize(self.raw, ptr::null_mut()) } != 0 {
+                return Err(TesseractError::RecognizeError);
+            }
+            self.recognized.set(true);
+        }
+        Ok(())
+    }
+
+    /// Wraps `TessBaseAPIGetHOCRText`. `page` is the zero-based page number
+    /// used in the generated ids.
+    pub fn get_hocr_text(&mut self, page: i32) -> Result<String> {
+        let text = unsafe { take_text(TessBaseAPIGetHOCRText(self.raw, page)) }
+            .ok_or(TesseractError::GetTextError)?;
+        self.recognized.set(true);
+        Ok(text)
+    }
+
+    /// Wraps `TessBaseAPIGetTsvText`: a row per page, block, paragraph, line
+    /// and word, without the header row. `page` is the zero-based page
+    /// number; the rows count pages from `page + 1`. `parse_tsv` reads the
+    /// rows back.
+    pub fn get_tsv_text(&mut self, page: i32) -> Result<String> {
+        let text = unsafe { take_text(TessBaseAPIGetTsvText(self.raw, page)) }
+            .ok_or(TesseractError::GetTextError)?;
+        self.recognized.set(true);
+        Ok(text)
+    }
+
+    /// `get_hocr_text`, but fails with `MissingDpi` instead of letting
+    /// tesseract guess the resolution of an image that has none. Tesseract
+    /// sizes and filters what it finds by the resolution, so a guess can shift
+    /// or drop words and their `bbox` attributes without any error.
+    pub fn hocr_validated(&mut self, page: i32) -> Result<String> {
+        if !self.resolution_set.get() {
+            return Err(TesseractError::MissingDpi);
+        }
+        self.get_hocr_text(page)
+    }
+
+    /// Recognises every page of `input` (an image or a multi-page TIFF) with
+    /// `TessBaseAPIProcessPages` and writes the result to `output_base` plus
+    /// the format's extension, e.g. `out.pdf` for an `output_base` of `out`.
+    ///
+    /// The PDF, hOCR and ALTO documents are titled with the file name of
+    /// `output_base` (`out` above) unless the `document_title` variable is
+    /// set. The output name (`set_output_name`) is set to `output_base` too.
+    ///
+    /// Replaces the current image; call `init` first.
+    pub fn ocr_to_file(
+        &mut self,
+        input: &Path,
+        output_base: &str,
+        format: OutputFormat,
+    ) -> Result<()> {
+        let title = Path::new(output_base)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        self.set_output_name(output_base)?;
+        self.render_pages(input, output_base, &title, format)
+    }
+
+    // Writes the document with `title` in place of an empty `document_title`,
+    // which is restored afterwards.
+    fn render_pages(
+        &mut self,
+        input: &Path,
+        output_base: &str,
+        title: &str,
+        format: OutputFormat,
+    ) -> Result<()> {
+        let c_output_base = CString::new(output_base)?;
+        let c_datadir = CString::new(self.datapath().unwrap_or_default())?;
+        let renderer = Renderer::new(format, &c_output_base, &c_datadir)
+            .ok_or(TesseractError::ProcessPagesError)?;
+        let set_title = self.string_variable("document_title").as_deref() == Some("");
+        if set_title {
+            self.set_variable("document_title", title)?;
+        }
+        let result = self.process_pages(input, &renderer);
+        if set_title {
+            let _ = self.set_variable("document_title", "");
+        }
+        result
+    }
+
+    /// Recognises every page of `input` (an image or a multi-page TIFF) with
+    /// `TessBaseAPIProcessPages`, writing them through `renderer` and every
+    /// renderer chained to it.
+    ///
+    /// The documents are only complete once the renderers are dropped, which
+    /// closes their files. Replaces the current image; call `init` first.
+    pub fn process_pages(&mut self, input: &Path, renderer: &Renderer) -> Result<()> {
+        let c_input = path_to_cstring(input)?;
+        let ok = unsafe {
+            TessBaseAPIProcessPages(
+                self.raw,
+                c_input.as_ptr(),
+                ptr::null(),
+                0,
+                renderer.as_ptr(),
+            )
+        };
+        self.recognized.set(false);
+        self.resolution_set.set(false);
+        self.image_size.set(None);
+        if ok != 0 {
+            Ok(())
+        } else {
+            Err(TesseractError::ProcessPagesError)
+        }
+    }
+
+    /// Like `ocr_to_file`, but copies the output into `writer`. The document
+    /// is titled with the file stem of `input`.
+    ///
+    /// The C API has no way to read a renderer's output back, so it is
+    /// rendered to a temporary file first, which is removed afterwards.
+    pub fn render_to_writer<W: Write>(
+        &mut self,
+        input: &Path,
+        format: OutputFormat,
+        writer: &mut W,
+    ) -> Result<()> {
+        let dir = TempDir::new()?;
+        let base = dir.path().join("output");
+        let output = base.with_extension(format.extension());
+        let title = input
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        self.render_pages(input, &base.to_string_lossy(), &title, format)?;
+        io::copy(&mut File::open(&output)?, writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Wraps `TessBaseAPIPrintVariablesToFile`: writes every variable with
+    /// its value and description to `path`, one per line, e.g.
+    /// `tessedit_pageseg_mode\t6\tPage seg mode: ...`.
+    ///
+    /// Tesseract dereferences its engine here, so this fails with
+    /// `InitError` before `init` rather than crashing.
+    pub fn print_variables_to_file(&self, path: &Path) -> Result<()> {
+        self.init_languages().ok_or(TesseractError::InitError)?;
+        let c_path = path_to_cstring(path)?;
+        if unsafe { TessBaseAPIPrintVariablesToFile(self.raw, c_path.as_ptr()) } != 0 {
+            Ok(())
+        } else {
+            Err(TesseractError::PrintVariablesError {
+                path: path.to_path_buf(),
+            })
+        }
+    }
+
+    /// Like `print_variables_to_file`, but copies the variables into
+    /// `writer`.
+    ///
+    /// The C API only prints to a file, so they are printed to a temporary
+    /// file first, which is removed afterwards.
+    pub fn print_variables<W: Write>(&self, writer: &mut W) -> Result<()> {
+        let dir = TempDir::new()?;
+        let path = dir.path().join("variables.txt");
+        self.print_variables_to_file(&path)?;
+        io::copy(&mut File::open(&path)?, writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Wraps `TessBaseAPIAnalyseLayout`: runs page layout analysis without
+    /// recognition. Returns `None` if there is no image or layout analysis
+    /// found nothing.
+    pub fn analyse_layout(&mut self) -> Option<PageIterator<'_>> {
+        // Replaces any recognised results with unrecognised ones.
+        self.recognized.set(false);
+        let raw = unsafe { TessBaseAPIAnalyseLayout(self.raw) };
+        if raw.is_null() {
+            None
+        } else {
+            Some(unsafe { PageIterator::from_raw(raw) })
+        }
+    }
+
+    /// Wraps `TessBaseAPIGetIterator`. Returns `None` until the image has
+    /// been recognised, even if layout analysis alone (`analyse_layout`,
+    /// `connected_components` and the like) has left results to iterate.
+    ///
+    /// The iterator reads tesseract's results in place, so it borrows the
+    /// API: anything that replaces the results, such as `set_image`,
+    /// `recognize`, `clear` or a `get_*` text function that recognises the
+    /// image first, can't be called while it is alive.
+    ///
+    /// ```compile_fail,E0502
+    /// # use tesseract_sys::api::TessBaseApi;
+    /// let mut api = TessBaseApi::new().unwrap();
+    /// let iterator = api.get_iterator();
+    /// api.clear();
+    /// drop(iterator);
+    /// ```
+    ///
+    /// ```compile_fail,E0502
+    /// # use tesseract_sys::api::TessBaseApi;
+    /// let mut api = TessBaseApi::new().unwrap();
+    /// let iterator = api.get_iterator();
+    /// let _ = api.get_utf8_text();
+    /// drop(iterator);
+    /// ```
+    pub fn get_iterator(&self) -> Option<ResultIterator<'_>> {
+        if !self.has_recognized() {
+            return None;
+        }
+        let raw = unsafe { TessBaseAPIGetIterator(self.raw) };
+        if raw.is_null() {
+            None
+        } else {
+            Some(unsafe { ResultIterator::from_raw(raw) })
+        }
+    }
+
+    /// Wraps `TessBaseAPIGetMutableIterator`. Returns `None` until the image
+    /// has been recognised.
+    ///
+    /// Takes `&mut self` so the API can't be used, nor other iterators
+    /// created, while the results may be changing.
+    pub fn mutable_iterator(&mut self) -> Option<MutableResultIterator<'_>> {
+        if !self.has_recognized() {
+            return None;
+        }
+        let raw = unsafe { TessBaseAPIGetMutableIterator(self.raw) };
+        if raw.is_null() {
+            None
+        } else {
+            Some(unsafe { MutableResultIterator::from_raw(raw) })
+        }
+    }
+
+    /// Bounding box and type of every block found by layout analysis, in
+    /// iterator order.
+    pub fn tagged_regions(&mut self) -> Vec<(Rect, PolyBlockType)> {
+        let mut regions = Vec::new();
+        if let Some(mut it) = self.analyse_layout() {
+            loop {
+                if let Some(rect) = it.bounding_box(PageIteratorLevel::RIL_BLOCK) {
+                    regions.push((rect, it.block_type()));
+                }
+                if !it.next(PageIteratorLevel::RIL_BLOCK) {
+                    break;
+                }
+            }
+        }
+        regions
+    }
+
+    /// Bounding box of every block found by layout analysis, in reading
+    /// order: the order the page iterator walks them in, which is kept
+    /// rather than sorted by position.
+    ///
+    /// Tesseract's order is column-aware: on a multi-column page it finishes
+    /// a column before moving to the next, so a block at the top of the
+    /// second column comes after the bottom of the first. Empty if there is
+    /// no image.
+    pub fn reading_order_blocks(&mut self) -> Vec<Rect> {
+        self.tagged_regions()
+            .into_iter()
+            .map(|(rect, _)| rect)
+            .collect()
+    }
+
+    /// Wraps `TessBaseAPIGetTextlines1`: the bounding box of every text line
+    /// in the thresholded image, in iterator order, with its block and
+    /// paragraph. Runs layout analysis if it hasn't run yet; empty if there
+    /// is no image.
+    pub fn textlines_with_ids(&self) -> Vec<TextLine> {
+        let mut block_ids: *mut c_int = ptr::null_mut();
+        let mut paragraph_ids: *mut c_int = ptr::null_mut();
+        let mut boxa = unsafe {
+            TessBaseAPIGetTextlines1(
+                self.raw,
+                0,
+                0,
+                ptr::null_mut(),
+                &mut block_ids,
+                &mut paragraph_ids,
+            )
+        };
+        let mut lines = Vec::new();
+        if !boxa.is_null() && !block_ids.is_null() && !paragraph_ids.is_null() {
+            let count = unsafe { boxaGetCount(boxa) };
+            for i in 0..count {
+                let (mut x, mut y, mut w, mut h) = (0, 0, 0, 0);
+                unsafe { boxaGetBoxGeometry(boxa, i, &mut x, &mut y, &mut w, &mut h) };
+                lines.push(TextLine {
+                    bbox: Rect {
+                        left: x,
+                        top: y,
+                        width: w,
+                        height: h,
+                    },
+                    block_id: unsafe { *block_ids.add(i as usize) },
+                    paragraph_id: unsafe { *paragraph_ids.add(i as usize) },
+                });
+            }
+        }
+        unsafe {
+            delete_int_array(block_ids);
+            delete_int_array(paragraph_ids);
+            if !boxa.is_null() {
+                boxaDestroy(&mut boxa);
+            }
+        }
+        lines
+    }
+
+    /// Wraps `TessBaseAPIGetConnectedComponents`: the bounding box of every
+    /// connected component of the thresholded image.
+    ///
+    /// This is part of layout analysis, not recognition: it runs layout
+    /// analysis if it hasn't run yet and doesn't need `recognize`. Empty if
+    /// there is no image.
+    pub fn connected_components(&self) -> Vec<Rect> {
+        let mut boxa = unsafe { TessBaseAPIGetConnectedComponents(self.raw, ptr::null_mut()) };
+        let rects = boxa_rects(boxa);
+        if !boxa.is_null() {
+            unsafe { boxaDestroy(&mut boxa) };
+        }
+        rects
+    }
+
+    /// Like `connected_components`, with each component's image cut out of
+    /// the thresholded image.
+    pub fn connected_components_with_pix(&self) -> Vec<(Rect, Pix)> {
+        let mut pixa: *mut Pixa = ptr::null_mut();
+        let mut boxa = unsafe { TessBaseAPIGetConnectedComponents(self.raw, &mut pixa) };
+        let mut components = Vec::new();
+        if !pixa.is_null() {
+            let rects = boxa_rects(boxa);
+            let count = unsafe { pixaGetCount(pixa) };
+            for (i, rect) in (0..count).zip(rects) {
+                // A clone is a new reference, which `Pix` then owns.
+                if let Some(pix) = unsafe { Pix::from_raw(pixaGetPix(pixa, i, L_CLONE as c_int)) } {
+                    components.push((rect, pix));
+                }
+            }
+        }
+        unsafe {
+            if !pixa.is_null() {
+                pixaDestroy(&mut pixa);
+            }
+            if !boxa.is_null() {
+                boxaDestroy(&mut boxa);
+            }
+        }
+        components
+    }
+
+    /// Wraps `TessBaseAPISetMinOrientationMargin`.
+    ///
+    /// The margin is how far the best orientation's score must beat the
+    /// runner-up before orientation detection reports it. Tesseract's default
+    /// (the `min_orientation_margin` variable) is `7.0`; lowering it makes
+    /// detection more eager to report a rotation on weak evidence.
+    pub fn set_min_orientation_margin(&self, margin: f64) {
+        unsafe { TessBaseAPISetMinOrientationMargin(self.raw, margin) }
+    }
+
+    /// Wraps `TessBaseAPIDetectOrientationScript`. Requires the `osd`
+    /// language data.
+    pub fn detect_orientation_script(&mut self) -> Result<OrientationScript> {
+        let mut orient_deg: c_int = 0;
+        let mut orient_conf: c_float = 0.0;
+        let mut script_name: *const c_char = ptr::null();
+        let mut script_conf: c_float = 0.0;
+        let ret = unsafe {
+            TessBaseAPIDetectOrientationScript(
+                self.raw,
+                &mut orient_deg,
+                &mut orient_conf,
+                &mut script_name,
+                &mut script_conf,
+            )
+        };
+        if ret == 0 {
+            return Err(TesseractError::DetectOrientationScriptError);
+        }
+        let script_name = if script_name.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(script_name) }
+                .to_string_lossy()
+                .into_owned()
+        };
+        let script = if script_name.is_empty() {
+            None
+        } else {
+            script_name.parse().ok()
+        };
+        Ok(OrientationScript {
+            orient_deg,
+            orient_conf,
+            script_name,
+            script,
+            script_conf,
+        })
+    }
+}
+
+/// Caps the threads an OpenMP-enabled tesseract uses per recognition, by
+/// setting `OMP_THREAD_LIMIT`. A limit of 1 avoids oversubscribing the CPU
+/// when many instances run side by side, e.g. in a `TessPool`.
+///
+/// There is no C API for this: the OpenMP runtime reads the variable once,
+/// when tesseract first runs in parallel. So this must be called before the
+/// first recognition in the process, and it applies to the whole process, not
+/// to one `TessBaseApi`. Call it early in `main`, before other threads start,
+/// as changing the environment races with threads reading it. Tesseract built
+/// without OpenMP (the default since 5.0) ignores it.
+///
+/// # Panics
+///
+/// If `limit` is 0.
+pub fn set_thread_limit(limit: usize) {
+    assert!(limit > 0, "the thread limit must be at least 1");
+    env::set_var("OMP_THREAD_LIMIT", limit.to_string());
+}
+
+/// Explains a failed init: names the first requested language whose
+/// `.traineddata` is missing from the datapath (or `TESSDATA_PREFIX` when no
+/// datapath was given), falling back to `InitError`.
+pub(crate) fn init_error(datapath: Option<&str>, language: &str) -> TesseractError {
+    let datapath = match datapath
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("TESSDATA_PREFIX").map(PathBuf::from))
+    {
+        Some(datapath) if datapath.is_dir() => datapath,
+        _ => return TesseractError::InitError,
+    };
+    language
+        .split('+')
+        .map(|lang| lang.trim_start_matches('~'))
+        .find(|lang| !lang.is_empty() && !datapath.join(format!("{}.traineddata", lang)).is_file())
+        .map_or(TesseractError::InitError, |lang| {
+            TesseractError::MissingLanguageData {
+                lang: lang.to_string(),
+                datapath,
+            }
+        })
+}
+
+/// A new directory in the temporary directory, for output the C API only
+/// writes to files. Removed with everything in it on drop.
+///
+/// Tesseract opens its output files without checking what is already there,
+/// so they must not go straight into a shared temporary directory, where
+/// another user could plant a symlink at a predictable name. The directory
+/// is created afresh, never reused, and on Unix only its owner can enter it.
+struct TempDir {
+    path: PathBuf,
+}
+
+impl TempDir {
+    fn new() -> io::Result<Self> {
+        static DIRS: AtomicUsize = AtomicUsize::new(0);
+        let mut builder = fs::DirBuilder::new();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::DirBuilderExt;
+            builder.mode(0o700);
+        }
+        let mut attempts = 0;
+        loop {
+            // RandomState is seeded randomly per process and varies between
+            // instances, which makes the name hard to guess.
+            let random = RandomState::new().build_hasher().finish();
+            let path = env::temp_dir().join(format!(
+                "tesseract-sys-{}-{}-{:016x}",
+                process::id(),
+                DIRS.fetch_add(1, Ordering::Relaxed),
+                random
+            ));
+            match builder.create(&path) {
+                Ok(()) => return Ok(Self { path }),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists && attempts < 16 => {
+                    attempts += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(unix)]
+fn path_to_cstring(path: &Path) -> Result<CString> {
+    use std::os::unix::ffi::OsStrExt;
+    Ok(CString::new(path.as_os_str().as_bytes())?)
+}
+
+// Tesseract opens files with the narrow-character C functions.
+#[cfg(not(unix))]
+fn path_to_cstring(path: &Path) -> Result<CString> {
+    Ok(CString::new(path.to_string_lossy().into_owned())?)
+}
+
+/// Copies and frees a string returned by one of the `Get*Text` functions.
+unsafe fn take_text(text: *mut c_char) -> Option<String> {
+    if text.is_null() {
+        return None;
+    }
+    let owned = CStr::from_ptr(text).to_string_lossy().into_owned();
+    TessDeleteText(text);
+    Some(owned)
+}
+
+/// Copies and frees a null-terminated string array such as the one returned
+/// by `TessBaseAPIGetLoadedLanguagesAsVector`.
+unsafe fn take_text_array(arr: *mut *mut c_char) -> Vec<String> {
+    let mut strings = Vec::new();
+    if arr.is_null() {
+        return strings;
+    }
+    let mut cursor = arr;
+    while !(*cursor).is_null() {
+        strings.push(CStr::from_ptr(*cursor).to_string_lossy().into_owned());
+        cursor = cursor.add(1);
+    }
+    delete_text_array(arr);
+    strings
+}
+
+/// The boxes of a `Boxa`, in order; empty if it is null. Doesn't free it.
+fn boxa_rects(boxa: *mut Boxa) -> Vec<Rect> {
+    if boxa.is_null() {
+        return Vec::new();
+    }
+    let count = unsafe { boxaGetCount(boxa) };
+    (0..count)
+        .map(|i| {
+            let (mut x, mut y, mut w, mut h) = (0, 0, 0, 0);
+            unsafe { boxaGetBoxGeometry(boxa, i, &mut x, &mut y, &mut w, &mut h) };
+            Rect {
+                left: x,
+                top: y,
+                width: w,
+                height: h,
+            }
+        })
+        .collect()
+}
+
+// Tesseract's progress monitor, deleted when dropped.
+struct Monitor {
+    raw: *mut TessMonitor,
+}
+
+impl Monitor {
+    fn new() -> Result<Self> {
+        let raw = unsafe { TessMonitorCreate() };
+        if raw.is_null() {
+            return Err(TesseractError::CreateError);
+        }
+        Ok(Self { raw })
+    }
+}
+
+impl Drop for Monitor {
+    fn drop(&mut self) {
+        unsafe { TessMonitorDelete(self.raw) }
+    }
+}
+
+// The monitor's cancel function, with the flag as its `cancel_this`. A panic
+// can't unwind into tesseract from here: loading an atomic doesn't panic.
+unsafe extern "C" fn cancel_requested(cancel_this: *mut c_void, _words: c_int) -> bool {
+    (*(cancel_this as *const AtomicBool)).load(Ordering::SeqCst)
+}
+
+// TessBaseAPISetPageSegMode already sets `tessedit_pageseg_mode` in current
+// tesseract; setting the variable as well only guards against a version that
+// doesn't.
+unsafe fn set_page_seg_mode(raw: *mut TessBaseAPI, mode: PageSegMode) {
+    TessBaseAPISetPageSegMode(raw, mode as TessPageSegMode);
+    let value = CString::new((mode as i32).to_string()).unwrap();
+    // Only fails for unknown variables, and creates the engine if needed.
+    TessBaseAPISetVariable(
+        raw,
+        b"tessedit_pageseg_mode\0".as_ptr().cast(),
+        value.as_ptr(),
+    );
+}
+
+// Sets a page segmentation mode back when dropped, including on early
+// returns.
+struct RestorePageSegMode {
+    raw: *mut TessBaseAPI,
+    previous: PageSegMode,
+}
+
+impl Drop for RestorePageSegMode {
+    fn drop(&mut self) {
+        unsafe { set_page_seg_mode(self.raw, self.previous) }
+    }
+}
+
+impl Drop for TessBaseApi {
+    fn drop(&mut self) {
+        unsafe { TessBaseAPIDelete(self.raw) }
+    }
+}
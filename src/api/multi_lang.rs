@@ -0,0 +1,74 @@
+use super::{PixelFormat, Result, TessBaseApi, TessConfig};
+use std::collections::HashMap;
+
+/// One API per language string, such as `eng` or `eng+fra`, all initialised
+/// from the same datapath and config.
+///
+/// Each API is initialised the first time its language is used and kept for
+/// later calls, so switching between languages doesn't reload any models.
+///
+/// Every API holds its own copy of the models of its languages, typically
+/// tens of megabytes per language, and nothing is shared between them:
+/// `eng`, `eng+fra` and `fra` load `eng` and `fra` twice each. Memory grows
+/// with every language string used, until the `MultiLangApi` is dropped.
+///
+/// ```no_run
+/// use tesseract_sys::api::{MultiLangApi, PixelFormat, TessConfig};
+///
+/// let mut apis = MultiLangApi::new(TessConfig::new(), None);
+/// # let (data, width, height) = (Vec::new(), 0, 0);
+/// let english = apis.ocr_with_lang("eng", &data, width, height, PixelFormat::Grayscale)?;
+/// let german = apis.ocr_with_lang("deu", &data, width, height, PixelFormat::Grayscale)?;
+/// # Ok::<(), tesseract_sys::api::TesseractError>(())
+/// ```
+pub struct MultiLangApi {
+    config: TessConfig,
+    datapath: Option<String>,
+    apis: HashMap<String, TessBaseApi>,
+}
+
+impl MultiLangApi {
+    /// APIs will be made with `config.build(datapath, language)`. None are
+    /// created yet.
+    pub fn new(config: TessConfig, datapath: Option<&str>) -> Self {
+        Self {
+            config,
+            datapath: datapath.map(str::to_string),
+            apis: HashMap::new(),
+        }
+    }
+
+    /// The language strings initialised so far, sorted.
+    pub fn languages(&self) -> Vec<&str> {
+        let mut languages: Vec<&str> = self.apis.keys().map(String::as_str).collect();
+        languages.sort_unstable();
+        languages
+    }
+
+    /// The API for `language`, initialising it if this is the first use.
+    /// A failed initialisation isn't kept, so the next call tries again.
+    pub fn api(&mut self, language: &str) -> Result<&mut TessBaseApi> {
+        if !self.apis.contains_key(language) {
+            let api = self.config.build(self.datapath.as_deref(), language)?;
+            self.apis.insert(language.to_string(), api);
+        }
+        Ok(self.apis.get_mut(language).unwrap())
+    }
+
+    /// Recognises an image with `set_image_fmt` in `language` and returns its
+    /// text.
+    pub fn ocr_with_lang(
+        &mut self,
+        language: &str,
+        data: &[u8],
+        width: i32,
+        height: i32,
+        format: PixelFormat,
+    ) -> Result<String> {
+        let api = self.api(language)?;
+        api.set_image_fmt(data, width, height, format)?;
+        let text = api.get_utf8_text();
+        api.clear();
+        text
+    }
+}
@@ -0,0 +1,71 @@
+use super::{path_to_cstring, Pix, Result, TessBaseApi, TesseractError};
+use leptonica_sys::pixReadFromMultipageTiff;
+use std::ffi::CString;
+use std::path::Path;
+
+/// The text of each page of a multipage TIFF, recognised one page at a time
+/// as the iterator advances.
+///
+/// Only the page being recognised is decoded, so memory doesn't grow with
+/// the length of the document, and a progress UI can show each page as soon
+/// as it's done rather than after the whole file. A page that can't be
+/// decoded yields `DecodeError` and ends the stream, as does a file that
+/// isn't a TIFF; one that fails to recognise yields its error and the stream
+/// moves on to the next page.
+///
+/// ```no_run
+/// use std::path::Path;
+/// use tesseract_sys::api::{PageTextStream, TessBaseApi};
+///
+/// let mut api = TessBaseApi::new()?;
+/// api.init(None, "eng")?;
+/// for (i, text) in PageTextStream::new(&mut api, Path::new("scan.tif"))?.enumerate() {
+///     println!("page {}: {}", i + 1, text?);
+/// }
+/// # Ok::<(), tesseract_sys::api::TesseractError>(())
+/// ```
+pub struct PageTextStream<'a> {
+    api: &'a mut TessBaseApi,
+    path: CString,
+    // Where leptonica reads the next page from; 0 once the last one is read.
+    offset: usize,
+    done: bool,
+}
+
+impl<'a> PageTextStream<'a> {
+    /// Recognises the pages of the TIFF at `path` with `api`, which must be
+    /// initialised. Nothing is read until the first call to `next`.
+    pub fn new(api: &'a mut TessBaseApi, path: &Path) -> Result<Self> {
+        Ok(Self {
+            api,
+            path: path_to_cstring(path)?,
+            offset: 0,
+            done: false,
+        })
+    }
+}
+
+impl Iterator for PageTextStream<'_> {
+    type Item = Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let page = unsafe {
+            Pix::from_raw(pixReadFromMultipageTiff(
+                self.path.as_ptr(),
+                &mut self.offset,
+            ))
+        };
+        let page = match page {
+            Some(page) => page,
+            None => {
+                self.done = true;
+                return Some(Err(TesseractError::DecodeError));
+            }
+        };
+        self.done = self.offset == 0;
+        Some(self.api.ocr_pix(&page))
+    }
+}
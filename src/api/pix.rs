@@ -0,0 +1,53 @@
+use leptonica_sys::{pixDestroy, pixGetDepth, pixGetHeight, pixGetWidth, pixGetXRes, pixGetYRes};
+
+/// Owned leptonica `Pix`, destroyed on drop.
+pub struct Pix {
+    raw: *mut leptonica_sys::Pix,
+}
+
+impl Pix {
+    /// Takes ownership of `raw`. Returns `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must be null or a valid `Pix` whose reference the caller owns.
+    pub unsafe fn from_raw(raw: *mut leptonica_sys::Pix) -> Option<Self> {
+        if raw.is_null() {
+            None
+        } else {
+            Some(Self { raw })
+        }
+    }
+
+    pub fn as_ptr(&self) -> *mut leptonica_sys::Pix {
+        self.raw
+    }
+
+    pub fn width(&self) -> i32 {
+        unsafe { pixGetWidth(self.raw) }
+    }
+
+    pub fn height(&self) -> i32 {
+        unsafe { pixGetHeight(self.raw) }
+    }
+
+    pub fn depth(&self) -> i32 {
+        unsafe { pixGetDepth(self.raw) }
+    }
+
+    /// Horizontal resolution in pixels per inch, 0 if unknown.
+    pub fn x_resolution(&self) -> i32 {
+        unsafe { pixGetXRes(self.raw) }
+    }
+
+    /// Vertical resolution in pixels per inch, 0 if unknown.
+    pub fn y_resolution(&self) -> i32 {
+        unsafe { pixGetYRes(self.raw) }
+    }
+}
+
+impl Drop for Pix {
+    fn drop(&mut self) {
+        unsafe { pixDestroy(&mut self.raw) }
+    }
+}
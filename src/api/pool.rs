@@ -0,0 +1,181 @@
+use super::{PixelFormat, Result, TessBaseApi, TessConfig};
+use std::sync::{Condvar, Mutex};
+
+/// How a `TessPool` resets its APIs between calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TessPoolConfig {
+    /// Clear an API's adaptive classifier (`clear_adaptive_classifier`) every
+    /// this many calls it served, so what the legacy engine learnt from one
+    /// document can't change the results of unrelated ones. 1, the default,
+    /// clears it after every call; 0 never does.
+    pub clear_adaptive_every: usize,
+}
+
+impl Default for TessPoolConfig {
+    fn default() -> Self {
+        Self {
+            clear_adaptive_every: 1,
+        }
+    }
+}
+
+/// Up to `size` APIs, all initialised the same way, shared between threads.
+///
+/// A `TessBaseApi` can move between threads but not be used from two at once,
+/// so each call checks an API out, uses it alone and hands it back. APIs are
+/// only created once every existing one is busy, and callers wait when all
+/// `size` of them are.
+///
+/// ```no_run
+/// use tesseract_sys::api::{PixelFormat, TessConfig, TessPool};
+///
+/// let pool = TessPool::new(TessConfig::new(), None, "eng", 4);
+/// let pages: Vec<(Vec<u8>, i32, i32)> = Vec::new();
+/// std::thread::scope(|s| {
+///     for (data, width, height) in &pages {
+///         let pool = &pool;
+///         s.spawn(move || pool.ocr_image(data, *width, *height, PixelFormat::Grayscale));
+///     }
+/// });
+/// ```
+pub struct TessPool {
+    config: TessConfig,
+    datapath: Option<String>,
+    language: String,
+    size: usize,
+    pool_config: TessPoolConfig,
+    state: Mutex<PoolState>,
+    returned: Condvar,
+}
+
+struct PoolState {
+    idle: Vec<PooledApi>,
+    created: usize,
+}
+
+struct PooledApi {
+    api: TessBaseApi,
+    // Calls served since the adaptive classifier was last cleared.
+    calls: usize,
+}
+
+impl TessPool {
+    /// A pool whose APIs are made with `config.build(datapath, language)`.
+    /// None are created yet. The APIs are reset as `TessPoolConfig::default()`
+    /// says, unless `pool_config` replaces it.
+    ///
+    /// # Panics
+    ///
+    /// If `size` is 0.
+    pub fn new(config: TessConfig, datapath: Option<&str>, language: &str, size: usize) -> Self {
+        assert!(size > 0, "a TessPool needs at least one API");
+        Self {
+            config,
+            datapath: datapath.map(str::to_string),
+            language: language.to_string(),
+            size,
+            pool_config: TessPoolConfig::default(),
+            state: Mutex::new(PoolState {
+                idle: Vec::new(),
+                created: 0,
+            }),
+            returned: Condvar::new(),
+        }
+    }
+
+    /// Sets how the APIs are reset between calls.
+    pub fn pool_config(mut self, pool_config: TessPoolConfig) -> Self {
+        self.pool_config = pool_config;
+        self
+    }
+
+    /// The most APIs the pool will create.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// How many APIs have been created so far.
+    pub fn created(&self) -> usize {
+        self.state.lock().unwrap().created
+    }
+
+    /// Runs `f` with an API no other thread is using, creating one if all are
+    /// busy and the pool isn't full, or waiting for one otherwise. Fails only
+    /// if creating an API does.
+    ///
+    /// The API is cleared before it goes back, so `f` can't see another
+    /// call's image or results, and its adaptive classifier is cleared as
+    /// often as the `TessPoolConfig` says.
+    pub fn with_api<R>(&self, f: impl FnOnce(&mut TessBaseApi) -> R) -> Result<R> {
+        let mut api = Checkout {
+            pool: self,
+            api: Some(self.checkout()?),
+        };
+        Ok(f(&mut api.api.as_mut().unwrap().api))
+    }
+
+    /// Recognises an image with `set_image_fmt` and returns its text.
+    pub fn ocr_image(
+        &self,
+        data: &[u8],
+        width: i32,
+        height: i32,
+        format: PixelFormat,
+    ) -> Result<String> {
+        self.with_api(|api| {
+            api.set_image_fmt(data, width, height, format)?;
+            api.get_utf8_text()
+        })?
+    }
+
+    fn checkout(&self) -> Result<PooledApi> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some(api) = state.idle.pop() {
+                return Ok(api);
+            }
+            if state.created < self.size {
+                break;
+            }
+            state = self.returned.wait(state).unwrap();
+        }
+        // Reserve the slot, then init without holding the lock: loading
+        // language data is slow.
+        state.created += 1;
+        drop(state);
+        self.config
+            .build(self.datapath.as_deref(), &self.language)
+            .map(|api| PooledApi { api, calls: 0 })
+            .map_err(|e| {
+                self.state.lock().unwrap().created -= 1;
+                self.returned.notify_one();
+                e
+            })
+    }
+
+    fn check_in(&self, mut pooled: PooledApi) {
+        pooled.api.clear();
+        pooled.calls += 1;
+        let every = self.pool_config.clear_adaptive_every;
+        if every > 0 && pooled.calls >= every {
+            pooled.api.clear_adaptive_classifier();
+            pooled.calls = 0;
+        }
+        self.state.lock().unwrap().idle.push(pooled);
+        self.returned.notify_one();
+    }
+}
+
+// Hands the API back even if the closure panics.
+struct Checkout<'a> {
+    pool: &'a TessPool,
+    api: Option<PooledApi>,
+}
+
+impl Drop for Checkout<'_> {
+    fn drop(&mut self) {
+        if let Some(api) = self.api.take() {
+            self.pool.check_in(api);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use super::{path_to_cstring, Result, TesseractError};
+use crate::{
+    TessAltoRendererCreate, TessDeleteResultRenderer, TessHOcrRendererCreate,
+    TessHOcrRendererCreate2, TessPDFRendererCreate, TessResultRenderer, TessResultRendererInsert,
+    TessTextRendererCreate, TessTsvRendererCreate,
+};
+use std::ffi::{CStr, CString};
+use std::mem;
+use std::os::raw::{c_char, c_int};
+use std::path::Path;
+
+/// Output written by `TessBaseApi::ocr_to_file`.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum OutputFormat {
+    Txt,
+    Hocr,
+    Pdf,
+    Alto,
+    Tsv,
+}
+
+impl OutputFormat {
+    /// Extension tesseract appends to the output base name.
+    pub fn extension(self) -> &'static str {
+        match self {
+            OutputFormat::Txt => "txt",
+            OutputFormat::Hocr => "hocr",
+            OutputFormat::Pdf => "pdf",
+            OutputFormat::Alto => "xml",
+            OutputFormat::Tsv => "tsv",
+        }
+    }
+}
+
+/// Owned `TessResultRenderer`, deleted on drop, which also closes its file.
+///
+/// Renderers write to an output base name plus their format's extension,
+/// e.g. `out.txt` for an `output_base` of `out`. Several can be chained with
+/// `insert` to render one recognition to several formats, and passed to
+/// `TessBaseApi::process_pages`.
+pub struct Renderer {
+    raw: *mut TessResultRenderer,
+}
+
+impl Renderer {
+    /// Creates the renderer for `format`, writing to `output_base` plus the
+    /// format's extension. The PDF renderer reads its font from `datadir`.
+    pub(crate) fn new(format: OutputFormat, output_base: &CStr, datadir: &CStr) -> Option<Self> {
+        let base = output_base.as_ptr();
+        Self::from_raw(unsafe {
+            match format {
+                OutputFormat::Txt => TessTextRendererCreate(base),
+                OutputFormat::Hocr => TessHOcrRendererCreate(base),
+                OutputFormat::Pdf => TessPDFRendererCreate(base, datadir.as_ptr(), 0),
+                OutputFormat::Alto => TessAltoRendererCreate(base),
+                OutputFormat::Tsv => TessTsvRendererCreate(base),
+            }
+        })
+    }
+
+    fn from_raw(raw: *mut TessResultRenderer) -> Option<Self> {
+        if raw.is_null() {
+            None
+        } else {
+            Some(Self { raw })
+        }
+    }
+
+    fn create(
+        output_base: &str,
+        create: impl FnOnce(*const c_char) -> *mut TessResultRenderer,
+    ) -> Result<Self> {
+        let c_output_base = CString::new(output_base)?;
+        Self::from_raw(create(c_output_base.as_ptr())).ok_or(TesseractError::RendererError)
+    }
+
+    /// Wraps `TessTextRendererCreate`: plain text.
+    pub fn text(output_base: &str) -> Result<Self> {
+        Self::create(output_base, |base| unsafe { TessTextRendererCreate(base) })
+    }
+
+    /// Wraps `TessHOcrRendererCreate2`: hOCR, with the font of each word
+    /// when `font_info` is set and the legacy engine recognised it.
+    pub fn hocr(output_base: &str, font_info: bool) -> Result<Self> {
+        Self::create(output_base, |base| unsafe {
+            TessHOcrRendererCreate2(base, font_info as c_int)
+        })
+    }
+
+    /// Wraps `TessPDFRendererCreate`: a searchable PDF, or only the invisible
+    /// text layer when `text_only` is set. The glyphless font is read from
+    /// `datadir`, usually `TessBaseApi::datapath`.
+    pub fn pdf(output_base: &str, datadir: &Path, text_only: bool) -> Result<Self> {
+        let c_datadir = path_to_cstring(datadir)?;
+        Self::create(output_base, |base| unsafe {
+            TessPDFRendererCreate(base, c_datadir.as_ptr(), text_only as c_int)
+        })
+    }
+
+    /// Wraps `TessAltoRendererCreate`: ALTO XML.
+    pub fn alto(output_base: &str) -> Result<Self> {
+        Self::create(output_base, |base| unsafe { TessAltoRendererCreate(base) })
+    }
+
+    /// Wraps `TessTsvRendererCreate`: tab-separated values.
+    pub fn tsv(output_base: &str) -> Result<Self> {
+        Self::create(output_base, |base| unsafe { TessTsvRendererCreate(base) })
+    }
+
+    /// Wraps `TessResultRendererInsert`: `next`, and any renderers chained to
+    /// it, render the same pages, straight after this one. Tesseract deletes
+    /// the chain with its first renderer, so `next` is consumed.
+    pub fn insert(&mut self, next: Renderer) {
+        unsafe { TessResultRendererInsert(self.raw, next.raw) };
+        mem::forget(next);
+    }
+
+    /// Raw handle, for the `TessResultRenderer*` functions of the C API.
+    pub fn as_ptr(&self) -> *mut TessResultRenderer {
+        self.raw
+    }
+}
+
+impl Drop for Renderer {
+    fn drop(&mut self) {
+        unsafe { TessDeleteResultRenderer(self.raw) }
+    }
+}
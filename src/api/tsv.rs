@@ -0,0 +1,119 @@
+use super::Rect;
+
+/// A row of tesseract's TSV output, as produced by
+/// `TessBaseApi::get_tsv_text` or the TSV renderer.
+///
+/// Every row describes one element of the page hierarchy. The numbers of the
+/// elements it belongs to are counted from 1, and are 0 below the row's own
+/// level, e.g. a line's `word_num`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TsvRow {
+    /// 1 for a page, 2 for a block, 3 for a paragraph, 4 for a line and 5
+    /// for a word.
+    pub level: i32,
+    pub page_num: i32,
+    pub block_num: i32,
+    pub par_num: i32,
+    pub line_num: i32,
+    pub word_num: i32,
+    pub bbox: Rect,
+    /// From 0 to 100, for words only. Tesseract writes -1 for the other
+    /// levels, which becomes `None`.
+    pub conf: Option<f32>,
+    /// The word, or an empty string for the other levels.
+    pub text: String,
+}
+
+impl TsvRow {
+    /// Level of the words, which are the only rows with text.
+    pub const WORD_LEVEL: i32 = 5;
+
+    fn parse(line: &str) -> Option<Self> {
+        let mut columns = line.splitn(12, '\t');
+        let mut int = || columns.next()?.trim().parse::<i32>().ok();
+        let (level, page_num, block_num, par_num, line_num, word_num) =
+            (int()?, int()?, int()?, int()?, int()?, int()?);
+        let bbox = Rect {
+            left: int()?,
+            top: int()?,
+            width: int()?,
+            height: int()?,
+        };
+        // An integer before tesseract 5, a float since.
+        let conf = columns.next()?.trim();
+        let conf = if conf.is_empty() {
+            None
+        } else {
+            Some(conf.parse::<f32>().ok()?)
+        };
+        Some(Self {
+            level,
+            page_num,
+            block_num,
+            par_num,
+            line_num,
+            word_num,
+            bbox,
+            conf: conf.filter(|conf| *conf >= 0.0),
+            text: columns.next().unwrap_or_default().to_string(),
+        })
+    }
+}
+
+/// Parses TSV output into its rows, in order.
+///
+/// The header row the TSV renderer starts with is skipped, as are blank lines
+/// and lines that don't have the numeric columns of a row. A missing or empty
+/// text column is an empty `text`.
+pub fn parse_tsv(tsv: &str) -> Vec<TsvRow> {
+    tsv.lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .filter(|line| !line.starts_with("level\t"))
+        .filter_map(TsvRow::parse)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows() {
+        let tsv = "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\tleft\ttop\twidth\theight\tconf\ttext\n\
+                   1\t1\t0\t0\t0\t0\t0\t0\t640\t480\t-1\t\n\
+                   4\t1\t1\t1\t1\t0\t10\t20\t300\t30\t-1\n\
+                   5\t1\t1\t1\t1\t1\t10\t20\t90\t30\t96.5\tHundreds\n\
+                   5\t1\t1\t1\t1\t2\t110\t20\t30\t30\t91\tof\r\n\
+                   \n\
+                   5\t1\t1\t1\t1\tx\t110\t20\t30\t30\t91\tbad\n";
+        let rows = parse_tsv(tsv);
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[0].level, 1);
+        assert_eq!(rows[0].bbox.to_ltrb(), (0, 0, 640, 480));
+        assert_eq!(rows[0].conf, None);
+        assert_eq!(rows[0].text, "");
+        assert_eq!(rows[1].text, "");
+        assert_eq!(
+            rows[2],
+            TsvRow {
+                level: TsvRow::WORD_LEVEL,
+                page_num: 1,
+                block_num: 1,
+                par_num: 1,
+                line_num: 1,
+                word_num: 1,
+                bbox: Rect {
+                    left: 10,
+                    top: 20,
+                    width: 90,
+                    height: 30,
+                },
+                conf: Some(96.5),
+                text: "Hundreds".to_string(),
+            }
+        );
+        assert_eq!(rows[3].conf, Some(91.0));
+        assert_eq!(rows[3].text, "of");
+    }
+}
@@ -2,19 +2,49 @@
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 
+#[cfg(feature = "leptonica-sys")]
 use leptonica_sys::*;
 
-include!(concat!(env!("OUT_DIR"), "/capi_bindings.rs"));
-include!(concat!(env!("OUT_DIR"), "/public_types_bindings.rs"));
+#[cfg(feature = "api")]
+pub mod api;
+#[cfg(feature = "api")]
+pub mod prelude;
+pub mod util;
+
+#[cfg(not(feature = "prebuilt-5_3_4"))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/",
+    env!("TESSERACT_BINDINGS_PREFIX"),
+    "capi_bindings.rs"
+));
+#[cfg(all(feature = "public-types", not(feature = "prebuilt-5_3_4")))]
+include!(concat!(
+    env!("OUT_DIR"),
+    "/",
+    env!("TESSERACT_BINDINGS_PREFIX"),
+    "public_types_bindings.rs"
+));
+
+// Bindings generated once from tesseract 5.3.4, for builds without bindgen.
+#[cfg(feature = "prebuilt-5_3_4")]
+include!("../prebuilt/capi_bindings_5_3_4.rs");
+#[cfg(all(feature = "prebuilt-5_3_4", not(feature = "leptonica-sys")))]
+include!("../prebuilt/leptonica_opaque.rs");
+#[cfg(all(feature = "public-types", feature = "prebuilt-5_3_4"))]
+include!("../prebuilt/public_types_5_3_4.rs");
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "leptonica-sys")]
     use leptonica_sys::{pixFreeData, pixRead};
+    #[cfg(feature = "leptonica-sys")]
     use std::ffi::CStr;
     use std::ptr;
 
     #[test]
+    #[cfg(feature = "leptonica-sys")]
     fn ocr() {
         unsafe {
             let cube = TessBaseAPICreate();
@@ -34,6 +64,42 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "leptonica-sys")]
+    fn leptonica_sys_pix() {
+        let _: unsafe extern "C" fn(*mut TessBaseAPI, *mut leptonica_sys::Pix) =
+            TessBaseAPISetImage2;
+    }
+
+    #[test]
+    #[cfg(not(feature = "leptonica-sys"))]
+    fn opaque_pix() {
+        assert_eq!(std::mem::size_of::<Pix>(), 0);
+        let _: unsafe extern "C" fn(*mut TessBaseAPI, *mut Pix) = TessBaseAPISetImage2;
+        // A pointer from a leptonica linked by other means needs a cast.
+        let from_elsewhere: *mut std::os::raw::c_void = ptr::null_mut();
+        let _: *mut Pix = from_elsewhere as *mut _;
+    }
+
+    #[test]
+    fn generated_functions() {
+        assert!(GENERATED_FUNCTIONS.contains(&"TessBaseAPIGetUTF8Text"));
+        assert!(GENERATED_FUNCTIONS.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    #[cfg(feature = "prebuilt-5_3_4")]
+    fn prebuilt_bindings() {
+        let _: unsafe extern "C" fn() -> *mut TessBaseAPI = TessBaseAPICreate;
+        let _: unsafe extern "C" fn(*mut TessBaseAPI) -> *mut std::os::raw::c_char =
+            TessBaseAPIGetUTF8Text;
+        let _: unsafe extern "C" fn(*mut TessBaseAPI, *mut Pix) = TessBaseAPISetImage2;
+        assert!(GENERATED_FUNCTIONS.contains(&"TessBaseAPIInit3"));
+        let version = unsafe { std::ffi::CStr::from_ptr(TessVersion()) };
+        assert!(version.to_str().unwrap().starts_with("5.3."));
+    }
+
+    #[test]
+    #[cfg(feature = "public-types")]
     #[allow(path_statements)]
     fn defined_constants() {
         kMinCredibleResolution;
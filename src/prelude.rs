@@ -0,0 +1,20 @@
+//! Commonly used types of the safe API, without the raw FFI symbols.
+//!
+//! ```no_run
+//! use tesseract_sys::prelude::*;
+//!
+//! let mut api = TessBaseApi::new()?;
+//! api.init(None, "eng")?;
+//! api.set_page_seg_mode(PageSegMode::PSM_AUTO);
+//! # let image = unsafe { Pix::from_raw(leptonica_sys::pixRead(b"img.png\0".as_ptr().cast())) }.unwrap();
+//! api.set_image_pix(&image);
+//! println!("{}", api.get_utf8_text()?);
+//! # Ok::<(), TesseractError>(())
+//! ```
+
+pub use crate::api::{
+    ImageThresholdingMethod, MultiLangApi, MutableResultIterator, OutputFormat, PageIterator,
+    PageTextStream, Pix, PixelFormat, Point, Rect, Renderer, ResultIterator, Script, TessBaseApi,
+    TessConfig, TessPool, TesseractError, Word,
+};
+pub use crate::{OcrEngineMode, PageIteratorLevel, PageSegMode, PolyBlockType};
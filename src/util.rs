@@ -0,0 +1,52 @@
+//! Helpers for freeing memory returned by the C API.
+//!
+//! Each pointer must be released with the function matching the allocator
+//! tesseract used for it; these helpers say which is which and ignore null.
+//!
+//! `TessDeleteBlockList` is not covered: the block list functions were removed
+//! from the C API in tesseract 5.
+
+use crate::{TessDeleteIntArray, TessDeleteText, TessDeleteTextArray};
+use std::os::raw::{c_char, c_int};
+
+/// Frees a string returned by `TessBaseAPIGetUTF8Text`, `TessBaseAPIGetHOCRText`
+/// and the other `Get*Text` functions, `TessBaseAPIRect` or
+/// `TessResultIteratorGetUTF8Text`.
+///
+/// # Safety
+///
+/// `text` must be null or a string returned by one of those functions that has
+/// not been freed yet.
+pub unsafe fn delete_text(text: *mut c_char) {
+    if !text.is_null() {
+        TessDeleteText(text);
+    }
+}
+
+/// Frees a null-terminated string array returned by
+/// `TessBaseAPIGetLoadedLanguagesAsVector` or
+/// `TessBaseAPIGetAvailableLanguagesAsVector`, including its strings.
+///
+/// # Safety
+///
+/// `arr` must be null or an array returned by one of those functions that has
+/// not been freed yet.
+pub unsafe fn delete_text_array(arr: *mut *mut c_char) {
+    if !arr.is_null() {
+        TessDeleteTextArray(arr);
+    }
+}
+
+/// Frees an int array returned by `TessBaseAPIAllWordConfidences`, or the block
+/// and paragraph id arrays of `TessBaseAPIGetTextlines`,
+/// `TessBaseAPIGetStrips` and `TessBaseAPIGetComponentImages`.
+///
+/// # Safety
+///
+/// `arr` must be null or an array returned by one of those functions that has
+/// not been freed yet.
+pub unsafe fn delete_int_array(arr: *mut c_int) {
+    if !arr.is_null() {
+        TessDeleteIntArray(arr);
+    }
+}
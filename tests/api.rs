@@ -0,0 +1,1537 @@
+#![cfg(feature = "api")]
+
+mod common;
+
+use common::{crop, fill_black, fixture, grey_fixture, page, paste};
+use leptonica_sys::{
+    pixConvertTo32, pixGetPixel, pixRotate90, pixScale, pixSetResolution, pixWriteTiff, IFF_TIFF,
+};
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tesseract_sys::api::{
+    parse_tsv, ImageThresholdingMethod, MultiLangApi, OutputFormat, PageTextStream, Pix,
+    PixelFormat, Rect, Renderer, Script, TessBaseApi, TessConfig, TessPool, TessPoolConfig,
+    TesseractError, TextLine, TsvRow,
+};
+use tesseract_sys::{OcrEngineMode, PageIteratorLevel, PageSegMode, PolyBlockType};
+
+/// 8 bit grey pixels of a fixture, one byte per pixel.
+fn grey_bytes(path: &str) -> (Vec<u8>, i32, i32) {
+    let grey = grey_fixture(path);
+    let mut bytes = Vec::with_capacity((grey.width() * grey.height()) as usize);
+    for y in 0..grey.height() {
+        for x in 0..grey.width() {
+            let mut value = 0;
+            unsafe { pixGetPixel(grey.as_ptr(), x, y, &mut value) };
+            bytes.push(value as u8);
+        }
+    }
+    (bytes, grey.width(), grey.height())
+}
+
+/// Colour pixels of a fixture: red, green and blue, followed by an opaque
+/// alpha byte when `alpha` is set.
+fn colour_bytes(path: &str, alpha: bool) -> (Vec<u8>, i32, i32) {
+    let image = fixture(path);
+    let rgb = unsafe { Pix::from_raw(pixConvertTo32(image.as_ptr())) }.unwrap();
+    let mut bytes = Vec::new();
+    for y in 0..rgb.height() {
+        for x in 0..rgb.width() {
+            let mut value = 0;
+            unsafe { pixGetPixel(rgb.as_ptr(), x, y, &mut value) };
+            bytes.extend_from_slice(&value.to_be_bytes()[..3]);
+            if alpha {
+                bytes.push(0xff);
+            }
+        }
+    }
+    (bytes, rgb.width(), rgb.height())
+}
+
+/// The text fixture with a large solid black square to its right.
+fn image_region_fixture() -> Pix {
+    let text = grey_fixture("img.png");
+    let side = text.height().max(400);
+    let page = page(text.width() + side + 100, side);
+    paste(&page, &text, 0, 0);
+    fill_black(
+        &page,
+        Rect {
+            left: text.width() + 100,
+            top: 0,
+            width: side,
+            height: side,
+        },
+    );
+    page
+}
+
+/// The text fixture twice, one copy below the other with a wide gap, so
+/// that each forms its own paragraph.
+fn two_paragraph_fixture() -> Pix {
+    let text = grey_fixture("img.png");
+    let (width, height) = (text.width(), text.height());
+    let gap = height;
+    let page = page(width, 2 * height + gap);
+    for top in [0, height + gap] {
+        paste(&page, &text, 0, top);
+    }
+    page
+}
+
+/// Two columns, each the text fixture twice, one copy below the other,
+/// with a wide gap between the columns.
+fn two_column_fixture() -> Pix {
+    let text = grey_fixture("img.png");
+    let (width, height) = (text.width(), text.height());
+    let gap = width / 5;
+    let page = page(2 * width + gap, 2 * height);
+    for left in [0, width + gap] {
+        for top in [0, height] {
+            paste(&page, &text, left, top);
+        }
+    }
+    page
+}
+
+/// The first line of the text fixture followed by a half-size copy of its
+/// first letter, raised like a footnote marker.
+fn footnote_fixture() -> Pix {
+    let text = grey_fixture("img.png");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&text);
+    let layout = api.analyse_layout().unwrap();
+    let line = crop(
+        &text,
+        layout
+            .bounding_box(PageIteratorLevel::RIL_TEXTLINE)
+            .unwrap(),
+    );
+    let letter = crop(
+        &text,
+        layout.bounding_box(PageIteratorLevel::RIL_SYMBOL).unwrap(),
+    );
+    let marker = unsafe { Pix::from_raw(pixScale(letter.as_ptr(), 0.5, 0.5)) }.unwrap();
+    let margin = 20;
+    let page = page(
+        line.width() + marker.width() + 3 * margin,
+        line.height() + 2 * margin,
+    );
+    paste(&page, &line, margin, margin);
+    paste(&page, &marker, margin + line.width() + 4, margin);
+    page
+}
+
+/// The first block, line or word of the text fixture, depending on `level`,
+/// with a white margin around it.
+fn first_element_fixture(level: PageIteratorLevel) -> Pix {
+    let text = grey_fixture("img.png");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&text);
+    let element = crop(
+        &text,
+        api.analyse_layout().unwrap().bounding_box(level).unwrap(),
+    );
+    let margin = 20;
+    let page = page(element.width() + 2 * margin, element.height() + 2 * margin);
+    paste(&page, &element, margin, margin);
+    page
+}
+
+#[test]
+fn detect_orientation_script_with_min_margin() {
+    require_tessdata!("osd");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "osd").unwrap();
+    let image = fixture("img.png");
+    let rotated = unsafe { Pix::from_raw(pixRotate90(image.as_ptr(), 1)) }.unwrap();
+    api.set_image_pix(&rotated);
+    api.set_min_orientation_margin(2.0);
+    let osd = api.detect_orientation_script().unwrap();
+    assert_eq!(osd.orient_deg % 180, 90);
+}
+
+#[test]
+fn detect_latin_script() {
+    require_tessdata!("osd");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "osd").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    let osd = api.detect_orientation_script().unwrap();
+    assert_eq!(osd.orient_deg, 0);
+    assert_eq!(osd.script_name, "Latin");
+    assert_eq!(osd.script, Some(Script::Latin));
+}
+
+#[test]
+fn image_size() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    assert_eq!(api.image_size(), None);
+    let white = vec![255; 640 * 480];
+    api.set_image_fmt(&white, 640, 480, PixelFormat::Grayscale)
+        .unwrap();
+    assert_eq!(api.image_size(), Some((640, 480)));
+
+    let image = fixture("img.png");
+    api.set_image_pix(&image);
+    assert_eq!(api.image_size(), Some((image.width(), image.height())));
+    api.clear();
+    assert_eq!(api.image_size(), None);
+}
+
+#[test]
+fn ocr_image_with_psm() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_page_seg_mode(PageSegMode::PSM_AUTO);
+    let (bytes, width, height) = grey_bytes("img.png");
+    let block = api
+        .ocr_image_with_psm(
+            &bytes,
+            width,
+            height,
+            PixelFormat::Grayscale,
+            PageSegMode::PSM_SINGLE_BLOCK,
+        )
+        .unwrap();
+    assert!(block.contains("Rust"));
+    assert_eq!(api.page_seg_mode(), PageSegMode::PSM_AUTO);
+    let line = api
+        .ocr_image_with_psm(
+            &bytes,
+            width,
+            height,
+            PixelFormat::Grayscale,
+            PageSegMode::PSM_SINGLE_LINE,
+        )
+        .unwrap();
+    // Read as a single line, the four lines don't come out as they are.
+    assert_ne!(line, block);
+    assert_eq!(api.page_seg_mode(), PageSegMode::PSM_AUTO);
+
+    // Restored when the image is rejected too.
+    assert!(api
+        .ocr_image_with_psm(
+            &[],
+            0,
+            0,
+            PixelFormat::Grayscale,
+            PageSegMode::PSM_SINGLE_WORD
+        )
+        .is_err());
+    assert_eq!(api.page_seg_mode(), PageSegMode::PSM_AUTO);
+}
+
+#[test]
+fn suppress_resolution_warning() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.suppress_resolution_warning().unwrap();
+    // Raw pixels carry no resolution.
+    let (bytes, width, height) = grey_bytes("img.png");
+    api.set_image_fmt(&bytes, width, height, PixelFormat::Grayscale)
+        .unwrap();
+    assert!(api.get_utf8_text().unwrap().contains("Rust"));
+}
+
+#[test]
+fn hocr_validated_requires_resolution() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    // Raw pixels carry no resolution.
+    let (bytes, width, height) = grey_bytes("img.png");
+    api.set_image_fmt(&bytes, width, height, PixelFormat::Grayscale)
+        .unwrap();
+    assert!(matches!(
+        api.hocr_validated(0),
+        Err(TesseractError::MissingDpi)
+    ));
+
+    api.set_source_resolution(300);
+    let hocr = api.hocr_validated(0).unwrap();
+    assert!(hocr.contains("class='ocr_page'"));
+    assert!(hocr.contains("<title>img</title>"));
+    assert!(hocr.contains("bbox 0 0"));
+    assert!(hocr_words(&hocr).contains(&"Rust".to_string()));
+
+    // A new image needs its resolution again.
+    api.set_image_fmt(&bytes, width, height, PixelFormat::Grayscale)
+        .unwrap();
+    assert!(matches!(
+        api.hocr_validated(0),
+        Err(TesseractError::MissingDpi)
+    ));
+}
+
+#[test]
+fn init_from_tessdata_dir() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let eng = PathBuf::from(api.datapath().unwrap()).join("eng.traineddata");
+    // Two models, the second being eng under another name.
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("two_models");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::copy(&eng, dir.join("eng.traineddata")).unwrap();
+    fs::copy(&eng, dir.join("eng_copy.traineddata")).unwrap();
+    fs::write(dir.join("notes.txt"), "not a model").unwrap();
+
+    let mut api = TessBaseApi::new().unwrap();
+    api.init_from_tessdata_dir(&dir, None).unwrap();
+    assert_eq!(api.init_languages().as_deref(), Some("eng+eng_copy"));
+    let mut loaded = api.loaded_languages();
+    loaded.sort();
+    assert_eq!(loaded, ["eng", "eng_copy"]);
+
+    api.init_from_tessdata_dir(&dir, Some(&["eng_copy"]))
+        .unwrap();
+    assert_eq!(api.loaded_languages(), ["eng_copy"]);
+    assert!(matches!(
+        api.init_from_tessdata_dir(&dir, Some(&["fra"])),
+        Err(TesseractError::MissingLanguageData { lang, .. }) if lang == "fra"
+    ));
+
+    let empty = Path::new(env!("CARGO_TARGET_TMPDIR")).join("no_models");
+    fs::create_dir_all(&empty).unwrap();
+    assert!(matches!(
+        api.init_from_tessdata_dir(&empty, None),
+        Err(TesseractError::NoLanguageData { .. })
+    ));
+}
+
+#[test]
+fn build_info() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let info = api.build_info();
+    println!("{:?}", info);
+    let (major, _) = info.major_minor().expect("unparsable version");
+    assert!(major >= 4);
+}
+
+#[test]
+fn crop_word_images() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let page = fixture("img.png");
+    api.set_image_pix(&page);
+    let page_bounds = Rect::from_ltrb(0, 0, page.width(), page.height());
+
+    let mut words = 0;
+    let mut layout = api.analyse_layout().unwrap();
+    loop {
+        let (crop, origin) = layout
+            .get_image(PageIteratorLevel::RIL_WORD, 2, &page)
+            .unwrap();
+        assert!(crop.width() > 0 && crop.height() > 0);
+        assert!(page_bounds.contains(origin));
+        let binary = layout
+            .get_binary_image(PageIteratorLevel::RIL_WORD)
+            .unwrap();
+        assert_eq!(binary.depth(), 1);
+        assert!(binary.width() > 0 && binary.height() > 0);
+        words += 1;
+        if !layout.next(PageIteratorLevel::RIL_WORD) {
+            break;
+        }
+    }
+    assert_eq!(words, include_str!("../img.txt").split_whitespace().count());
+}
+
+#[test]
+fn group_words_into_lines() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+
+    let mut line_count = 0;
+    {
+        let mut layout = api.analyse_layout().unwrap();
+        loop {
+            line_count += 1;
+            if !layout.next(PageIteratorLevel::RIL_TEXTLINE) {
+                break;
+            }
+        }
+    }
+
+    let mut lines: Vec<Vec<Rect>> = Vec::new();
+    let mut layout = api.analyse_layout().unwrap();
+    loop {
+        if layout.is_at_beginning_of(PageIteratorLevel::RIL_TEXTLINE) {
+            lines.push(Vec::new());
+        }
+        let word = layout.bounding_box(PageIteratorLevel::RIL_WORD).unwrap();
+        let line = lines.last_mut().unwrap();
+        line.push(word);
+        if layout.is_at_final_element(PageIteratorLevel::RIL_TEXTLINE, PageIteratorLevel::RIL_WORD)
+        {
+            let bounds = layout
+                .bounding_box(PageIteratorLevel::RIL_TEXTLINE)
+                .unwrap();
+            assert!(line.iter().all(|word| bounds.intersects(word)));
+        }
+        if !layout.next(PageIteratorLevel::RIL_WORD) {
+            break;
+        }
+    }
+    assert_eq!(lines.len(), line_count);
+    assert_eq!(lines.len(), include_str!("../img.txt").lines().count());
+    assert!(lines.iter().all(|line| line.len() > 1));
+}
+
+#[test]
+fn recognize_cancellable() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    // Tesseract checks the flag before each word, so one already set stops
+    // recognition at the first.
+    api.set_image_pix(&fixture("img.png"));
+    let cancel = Arc::new(AtomicBool::new(true));
+    assert!(matches!(
+        api.recognize_cancellable(Arc::clone(&cancel)),
+        Err(TesseractError::Cancelled)
+    ));
+    assert!(!api.has_recognized());
+    cancel.store(false, Ordering::SeqCst);
+    api.recognize_cancellable(cancel).unwrap();
+    assert!(api.has_recognized());
+}
+
+#[test]
+fn page_text_stream() {
+    require_tessdata!("eng");
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("two_pages.tif");
+    let c_path = CString::new(path.to_str().unwrap()).unwrap();
+    let pages = [
+        fixture("img.png"),
+        first_element_fixture(PageIteratorLevel::RIL_TEXTLINE),
+    ];
+    for (page, mode) in pages.iter().zip([b"w\0", b"a\0"]) {
+        let ret = unsafe {
+            pixWriteTiff(
+                c_path.as_ptr(),
+                page.as_ptr(),
+                IFF_TIFF as i32,
+                mode.as_ptr().cast(),
+            )
+        };
+        assert_eq!(ret, 0);
+    }
+
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let texts: Vec<String> = PageTextStream::new(&mut api, &path)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let expected = include_str!("../img.txt");
+    assert_eq!(texts.len(), 2);
+    assert_eq!(texts[0], expected);
+    assert_eq!(texts[1].trim_end(), expected.lines().next().unwrap());
+
+    let mut garbage = PageTextStream::new(&mut api, Path::new("img.txt")).unwrap();
+    assert!(matches!(
+        garbage.next(),
+        Some(Err(TesseractError::DecodeError))
+    ));
+    assert!(garbage.next().is_none());
+}
+
+#[test]
+fn ocr_pix() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    for _ in 0..2 {
+        let image = fixture("img.png");
+        assert_eq!(api.ocr_pix(&image).unwrap(), include_str!("../img.txt"));
+    }
+    assert!(api.has_recognized());
+}
+
+#[test]
+fn set_image_pix_resolution() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let image = fixture("img.png");
+    unsafe { pixSetResolution(image.as_ptr(), 300, 300) };
+    api.set_image_pix(&image);
+    assert_eq!(api.source_y_resolution(), Some(300));
+    assert!(api.hocr_validated(0).is_ok());
+
+    api.set_image_pix_with_resolution(&image, 150);
+    assert_eq!(api.source_y_resolution(), Some(150));
+    assert!(api
+        .ocr_pix_with_resolution(&image, 200)
+        .unwrap()
+        .contains("Rust"));
+    assert_eq!(api.source_y_resolution(), Some(200));
+}
+
+#[test]
+fn digits_preset() {
+    require_tessdata!("eng");
+    let mut api = TessConfig::digits().build(None, "eng").unwrap();
+    let line = first_element_fixture(PageIteratorLevel::RIL_TEXTLINE);
+    let text = api.ocr_pix(&line).unwrap();
+    assert!(
+        text.chars()
+            .all(|c| c.is_ascii_digit() || c.is_whitespace()),
+        "{:?}",
+        text
+    );
+}
+
+#[test]
+fn single_word_preset() {
+    require_tessdata!("eng");
+    let mut api = TessConfig::single_word().build(None, "eng").unwrap();
+    let word = first_element_fixture(PageIteratorLevel::RIL_WORD);
+    assert_eq!(api.ocr_pix(&word).unwrap().trim(), "Hundreds");
+}
+
+#[test]
+fn pool_ocr_from_many_threads() {
+    require_tessdata!("eng");
+    let pool = TessPool::new(TessConfig::new(), None, "eng", 3);
+    let (bytes, width, height) = grey_bytes("img.png");
+    let expected = include_str!("../img.txt");
+    let in_use = Mutex::new(HashSet::new());
+    thread::scope(|s| {
+        for _ in 0..8 {
+            s.spawn(|| {
+                for _ in 0..2 {
+                    let text = pool
+                        .with_api(|api| {
+                            let raw = api.as_ptr() as usize;
+                            assert!(in_use.lock().unwrap().insert(raw), "API shared");
+                            api.set_image_fmt(&bytes, width, height, PixelFormat::Grayscale)
+                                .unwrap();
+                            let text = api.get_utf8_text().unwrap();
+                            in_use.lock().unwrap().remove(&raw);
+                            text
+                        })
+                        .unwrap();
+                    assert_eq!(text, expected);
+                    assert_eq!(
+                        pool.ocr_image(&bytes, width, height, PixelFormat::Grayscale)
+                            .unwrap(),
+                        expected
+                    );
+                }
+            });
+        }
+    });
+    assert!(pool.created() <= pool.size());
+}
+
+#[test]
+fn pool_resets_between_documents() {
+    require_tessdata!("eng");
+    // Only the legacy engine adapts to what it reads.
+    let config = TessConfig::new().oem(OcrEngineMode::OEM_TESSERACT_ONLY);
+    if config.build(None, "eng").is_err() {
+        // e.g. tessdata_fast, which has no legacy model.
+        eprintln!("skipping: no legacy model for `eng`");
+        return;
+    }
+    // The confidences show the adaptation even where the text doesn't.
+    let ocr = |api: &mut TessBaseApi, image: &Pix| {
+        api.set_image_pix(image);
+        let text = api.get_utf8_text().unwrap();
+        (text, api.all_word_confidences().unwrap())
+    };
+    let document_a = two_paragraph_fixture();
+    let document_b = fixture("img.png");
+    let expected = ocr(&mut config.build(None, "eng").unwrap(), &document_b);
+    let after_a = |clear_adaptive_every| {
+        let pool = TessPool::new(config.clone(), None, "eng", 1).pool_config(TessPoolConfig {
+            clear_adaptive_every,
+        });
+        let first = pool
+            .with_api(|api| {
+                ocr(api, &document_a);
+                api.as_ptr()
+            })
+            .unwrap();
+        let (second, output) = pool
+            .with_api(|api| (api.as_ptr(), ocr(api, &document_b)))
+            .unwrap();
+        assert_eq!(first, second, "API not reused");
+        output
+    };
+
+    // Without the clear, what was learnt from A carries over to B.
+    assert_ne!(after_a(0), expected);
+    assert_eq!(after_a(1), expected);
+}
+
+#[test]
+fn multi_lang_initialises_each_language_once() {
+    require_tessdata!("eng");
+    require_tessdata!("osd");
+    let mut apis = MultiLangApi::new(TessConfig::new(), None);
+    let (bytes, width, height) = grey_bytes("img.png");
+    let expected = include_str!("../img.txt");
+    let ocr = |apis: &mut MultiLangApi, language| {
+        apis.ocr_with_lang(language, &bytes, width, height, PixelFormat::Grayscale)
+            .unwrap()
+    };
+    assert_eq!(ocr(&mut apis, "eng"), expected);
+    let eng = apis.api("eng").unwrap().as_ptr();
+    ocr(&mut apis, "osd");
+    assert_eq!(apis.languages(), ["eng", "osd"]);
+    assert_eq!(ocr(&mut apis, "eng"), expected);
+    assert_eq!(apis.languages(), ["eng", "osd"]);
+    assert_eq!(apis.api("eng").unwrap().as_ptr(), eng);
+    assert_eq!(
+        apis.api("osd").unwrap().init_languages().as_deref(),
+        Some("osd")
+    );
+}
+
+#[test]
+fn textlines_grouped_by_paragraph() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&two_paragraph_fixture());
+    let lines = api.textlines_with_ids();
+    let per_copy = include_str!("../img.txt").lines().count();
+    assert_eq!(lines.len(), 2 * per_copy);
+
+    let (first, second) = lines.split_at(per_copy);
+    let paragraph = |line: &TextLine| (line.block_id, line.paragraph_id);
+    assert!(first
+        .iter()
+        .all(|line| paragraph(line) == paragraph(&first[0])));
+    assert!(second
+        .iter()
+        .all(|line| paragraph(line) == paragraph(&second[0])));
+    assert_ne!(paragraph(&first[0]), paragraph(&second[0]));
+    let first_bottom = first.iter().map(|line| line.bbox.bottom()).max().unwrap();
+    assert!(second.iter().all(|line| line.bbox.top > first_bottom));
+}
+
+#[test]
+fn connected_components() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    assert!(api.connected_components().is_empty());
+
+    let image = fixture("img.png");
+    api.set_image_pix(&image);
+    let components = api.connected_components();
+    // Glyphs like "i" have more than one component.
+    let glyphs = include_str!("../img.txt")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .count();
+    assert!(components.len() >= glyphs);
+    assert!(components
+        .iter()
+        .all(|rect| rect.right() <= image.width() && rect.bottom() <= image.height()));
+    assert!(!api.has_recognized());
+
+    let with_pix = api.connected_components_with_pix();
+    assert_eq!(with_pix.len(), components.len());
+    for (rect, pix) in &with_pix {
+        assert_eq!((pix.width(), pix.height()), (rect.width, rect.height));
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn recognize_structured_to_json() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    let words = api.recognize_structured().unwrap();
+    let expected: Vec<&str> = include_str!("../img.txt").split_whitespace().collect();
+    assert_eq!(
+        words
+            .iter()
+            .map(|word| word.text.as_str())
+            .collect::<Vec<_>>(),
+        expected
+    );
+    assert!(words
+        .iter()
+        .all(|word| (0.0..=100.0).contains(&word.confidence) && !word.bbox.is_empty()));
+
+    let json = serde_json::to_string(&words).unwrap();
+    assert!(json.contains("\"text\":\"Rust\""));
+    for key in [
+        "bbox",
+        "left",
+        "top",
+        "width",
+        "height",
+        "confidence",
+        "font",
+    ] {
+        assert!(
+            json.contains(&format!("\"{}\":", key)),
+            "no {} in {}",
+            key,
+            json
+        );
+    }
+}
+
+#[test]
+fn words_with_confidences() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    let words = api.words_with_confidences().unwrap();
+    let expected: Vec<&str> = include_str!("../img.txt").split_whitespace().collect();
+    assert_eq!(
+        words
+            .iter()
+            .map(|(word, _)| word.as_str())
+            .collect::<Vec<_>>(),
+        expected
+    );
+    // A clean rendering of dictionary words is recognised with confidence.
+    assert!(words
+        .iter()
+        .all(|(_, confidence)| (50..=100).contains(confidence)));
+    let confidences: Vec<i32> = words.iter().map(|(_, confidence)| *confidence).collect();
+    assert_eq!(api.all_word_confidences().unwrap(), confidences);
+}
+
+#[test]
+fn iterator_confidence() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    api.recognize().unwrap();
+    let mut it = api.get_iterator().unwrap();
+    let confidence = it.confidence(PageIteratorLevel::RIL_WORD).unwrap();
+    assert!((0.0..=100.0).contains(&confidence));
+    while it.next(PageIteratorLevel::RIL_WORD) {}
+    // Past the last word there is nothing to be confident about.
+    assert_eq!(it.confidence(PageIteratorLevel::RIL_WORD), None);
+}
+
+#[test]
+fn parse_tsv_output() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let image = fixture("img.png");
+    api.set_image_pix(&image);
+    let rows = parse_tsv(&api.get_tsv_text(0).unwrap());
+    assert_eq!(rows[0].level, 1);
+    assert_eq!(rows[0].page_num, 1);
+    let words: Vec<&TsvRow> = rows
+        .iter()
+        .filter(|row| row.level == TsvRow::WORD_LEVEL)
+        .collect();
+    assert_eq!(
+        words.len(),
+        include_str!("../img.txt").split_whitespace().count()
+    );
+    assert!(words.iter().all(|word| !word.text.is_empty()
+        && !word.bbox.is_empty()
+        && word.bbox.right() <= image.width()
+        && word.bbox.bottom() <= image.height()
+        && word.conf.is_some_and(|conf| (0.0..=100.0).contains(&conf))));
+    assert_eq!(words[0].text, "Hundreds");
+}
+
+#[test]
+fn print_variables() {
+    let api = TessBaseApi::new().unwrap();
+    assert!(matches!(
+        api.print_variables(&mut Vec::new()),
+        Err(TesseractError::InitError)
+    ));
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_variable("tessedit_char_whitelist", "abc").unwrap();
+    let mut out = Vec::new();
+    api.print_variables(&mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    assert!(out
+        .lines()
+        .any(|line| line.starts_with("tessedit_char_whitelist\tabc\t")));
+
+    let missing = Path::new("no/such/dir/variables.txt");
+    match api.print_variables_to_file(missing) {
+        Err(TesseractError::PrintVariablesError { path }) => assert_eq!(path, missing),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn config_whitelist_and_psm() {
+    require_tessdata!("eng");
+    let mut api = TessConfig::new()
+        .whitelist("abcdefghijklmnopqrstuvwxyz")
+        .psm(PageSegMode::PSM_SINGLE_BLOCK)
+        .build(None, "eng")
+        .unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    let text = api.get_utf8_text().unwrap();
+    assert!(!text.trim().is_empty());
+    assert!(text
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_whitespace()));
+}
+
+#[test]
+fn char_whitelist_and_blacklist() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_char_whitelist("0123456789").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    let text = api.get_utf8_text().unwrap();
+    assert!(text
+        .chars()
+        .all(|c| c.is_ascii_digit() || c.is_whitespace()));
+
+    api.clear_char_whitelist().unwrap();
+    api.set_char_blacklist("Rr").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    let text = api.get_utf8_text().unwrap();
+    assert!(text.contains("ust"));
+    assert!(!text.contains(['R', 'r']));
+}
+
+#[test]
+fn tagged_regions() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let page = image_region_fixture();
+    api.set_image_pix(&page);
+    let regions = api.tagged_regions();
+    assert!(regions
+        .iter()
+        .any(|(_, block_type)| *block_type == PolyBlockType::PT_FLOWING_TEXT));
+    assert!(regions.iter().any(|(_, block_type)| !matches!(
+        block_type,
+        PolyBlockType::PT_FLOWING_TEXT
+            | PolyBlockType::PT_HEADING_TEXT
+            | PolyBlockType::PT_PULLOUT_TEXT
+            | PolyBlockType::PT_CAPTION_TEXT
+            | PolyBlockType::PT_VERTICAL_TEXT
+    )));
+    for (rect, _) in regions {
+        assert!(rect.width > 0 && rect.height > 0);
+        assert!(rect.left + rect.width <= page.width());
+    }
+}
+
+#[test]
+fn reading_order_blocks() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let page = two_column_fixture();
+    api.set_image_pix(&page);
+    let blocks = api.reading_order_blocks();
+    let middle = page.width() / 2;
+    let in_left_column = |rect: &Rect| rect.right() <= middle;
+    let left_blocks = blocks.iter().filter(|rect| in_left_column(rect)).count();
+    assert!(left_blocks > 0 && left_blocks < blocks.len());
+    // The left column's blocks all come first, although the right column's
+    // start at the top of the page.
+    assert!(blocks[..left_blocks].iter().all(in_left_column));
+    assert!(blocks[left_blocks..].iter().all(|rect| rect.left >= middle));
+}
+
+#[test]
+fn set_image_copies_pixels() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let (mut bytes, width, height) = grey_bytes("img.png");
+    api.set_image(&bytes, width, height, 1, width).unwrap();
+    // Scribble over the buffer before freeing it, so reading it afterwards
+    // couldn't go unnoticed.
+    bytes.iter_mut().for_each(|b| *b = 0);
+    drop(bytes);
+    assert_eq!(api.get_utf8_text().unwrap(), include_str!("../img.txt"));
+}
+
+#[test]
+fn set_image_fmt() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let expected = include_str!("../img.txt");
+
+    let (bytes, width, height) = grey_bytes("img.png");
+    api.set_image_fmt(&bytes, width, height, PixelFormat::Grayscale)
+        .unwrap();
+    assert_eq!(api.get_utf8_text().unwrap(), expected);
+
+    let (bytes, width, height) = colour_bytes("img.png", false);
+    api.set_image_fmt(&bytes, width, height, PixelFormat::Rgb)
+        .unwrap();
+    assert_eq!(api.get_utf8_text().unwrap(), expected);
+
+    let (bytes, width, height) = colour_bytes("img.png", true);
+    api.set_image_fmt(&bytes, width, height, PixelFormat::Rgba)
+        .unwrap();
+    assert_eq!(api.get_utf8_text().unwrap(), expected);
+
+    // RGB data read as RGBA runs out of bytes.
+    let (bytes, width, height) = colour_bytes("img.png", false);
+    assert!(matches!(
+        api.set_image_fmt(&bytes, width, height, PixelFormat::Rgba),
+        Err(TesseractError::InvalidImage(_))
+    ));
+}
+
+#[cfg(tesseract_5)]
+#[test]
+fn init_from_memory() {
+    require_tessdata!("eng");
+    // No model is small enough to check in, so load the installed one into
+    // memory the way an embedded `include_bytes!` model would be.
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let model = Path::new(&api.datapath().unwrap()).join("eng.traineddata");
+    let data = fs::read(model).unwrap();
+
+    let mut api = TessBaseApi::new().unwrap();
+    api.init_from_memory(&data, "eng", OcrEngineMode::OEM_DEFAULT)
+        .unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    assert_eq!(api.get_utf8_text().unwrap(), include_str!("../img.txt"));
+
+    assert!(matches!(
+        TessBaseApi::new()
+            .unwrap()
+            .init_from_memory(&[], "eng", OcrEngineMode::OEM_DEFAULT),
+        Err(TesseractError::InitError)
+    ));
+}
+
+#[test]
+fn sauvola_on_low_contrast_image() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    // Squeeze the fixture into a narrow band of light greys.
+    let (bytes, width, height) = grey_bytes("img.png");
+    let faded: Vec<u8> = bytes
+        .iter()
+        .map(|&v| 160 + (u32::from(v) * 60 / 255) as u8)
+        .collect();
+    api.set_thresholding_method(ImageThresholdingMethod::Sauvola)
+        .unwrap();
+    api.set_image(&faded, width, height, 1, width).unwrap();
+    assert!(!api.get_utf8_text().unwrap().trim().is_empty());
+}
+
+#[test]
+fn set_unknown_variable() {
+    let api = TessBaseApi::new().unwrap();
+    assert!(matches!(
+        api.set_variable("not_a_tesseract_variable", "1"),
+        Err(TesseractError::SetVariableError { .. })
+    ));
+}
+
+#[test]
+fn interior_nul_is_rejected_everywhere() {
+    fn assert_nul<T>(result: Result<T, TesseractError>) {
+        match result {
+            Err(TesseractError::NulError(_)) => {}
+            Err(e) => panic!("unexpected error {:?}", e),
+            Ok(_) => panic!("interior NUL accepted"),
+        }
+    }
+    let nul = "e\0ng";
+    let mut api = TessBaseApi::new().unwrap();
+    assert_nul(api.init(None, nul));
+    assert_nul(api.init(Some(nul), "eng"));
+    assert_nul(api.init_default(nul));
+    assert_nul(api.reinit(None, nul, OcrEngineMode::OEM_DEFAULT));
+    assert_nul(api.reinit(Some(nul), "eng", OcrEngineMode::OEM_DEFAULT));
+    #[cfg(tesseract_5)]
+    assert_nul(api.init_from_memory(b"model", nul, OcrEngineMode::OEM_DEFAULT));
+    assert_nul(api.set_variable(nul, "1"));
+    assert_nul(api.set_variable("tessedit_char_whitelist", nul));
+    assert_nul(api.set_char_whitelist(nul));
+    assert_nul(api.set_char_blacklist(nul));
+    assert_nul(api.set_output_name(nul));
+    assert_nul(api.ocr_to_file(Path::new("img.png"), nul, OutputFormat::Txt));
+    #[cfg(unix)]
+    {
+        assert_nul(api.ocr_to_file(Path::new(nul), "out", OutputFormat::Txt));
+        assert_nul(api.render_to_writer(Path::new(nul), OutputFormat::Txt, &mut Vec::new()));
+    }
+    assert_nul(TessConfig::new().build(None, nul));
+    assert_nul(TessConfig::new().build(Some(nul), "eng"));
+    assert_nul(TessConfig::new().variable(nul, "1").build(None, "eng"));
+    assert_nul(TessConfig::new().whitelist(nul).build(None, "eng"));
+    assert_nul(TessConfig::new().config(nul).build(None, "eng"));
+}
+
+#[test]
+fn init_with_oem() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init_with_oem(None, "eng", OcrEngineMode::OEM_LSTM_ONLY)
+        .unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    assert!(api.get_utf8_text().unwrap().contains("Rust"));
+
+    let empty = Path::new(env!("CARGO_TARGET_TMPDIR")).join("init_with_oem");
+    fs::create_dir_all(&empty).unwrap();
+    match TessBaseApi::new().unwrap().init_with_oem(
+        empty.to_str(),
+        "eng",
+        OcrEngineMode::OEM_LSTM_ONLY,
+    ) {
+        Err(TesseractError::MissingLanguageData { lang, datapath }) => {
+            assert_eq!(lang, "eng");
+            assert_eq!(datapath, empty);
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert!(matches!(
+        TessBaseApi::new().unwrap().init_with_oem(
+            Some("/nonexistent/tessdata"),
+            "eng",
+            OcrEngineMode::OEM_LSTM_ONLY
+        ),
+        Err(TesseractError::InitError)
+    ));
+}
+
+#[test]
+fn reinit_with_other_engine_mode() {
+    require_tessdata!("eng");
+    let mut api = TessConfig::new()
+        .oem(OcrEngineMode::OEM_LSTM_ONLY)
+        .build(None, "eng")
+        .unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    assert!(api.get_utf8_text().unwrap().contains("Rust"));
+
+    api.reinit(None, "eng", OcrEngineMode::OEM_DEFAULT).unwrap();
+    assert!(!api.has_recognized());
+    api.set_image_pix(&fixture("img.png"));
+    assert!(api.get_utf8_text().unwrap().contains("Rust"));
+}
+
+#[test]
+fn set_engine_mode() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    assert!(api.engine_mode().is_none());
+    assert!(matches!(
+        api.set_engine_mode(OcrEngineMode::OEM_LSTM_ONLY),
+        Err(TesseractError::InitError)
+    ));
+    if api
+        .init_with_oem(None, "eng", OcrEngineMode::OEM_TESSERACT_LSTM_COMBINED)
+        .is_err()
+    {
+        // e.g. tessdata_fast, which has no legacy model.
+        eprintln!("skipping: no legacy model for `eng`");
+        return;
+    }
+
+    // `api` has both engines loaded and switches through the variable;
+    // `reinit` only has the LSTM model and is re-initialised.
+    let mut reinit = TessBaseApi::new().unwrap();
+    reinit
+        .init_with_oem(None, "eng", OcrEngineMode::OEM_LSTM_ONLY)
+        .unwrap();
+    for api in [&mut api, &mut reinit] {
+        for oem in [
+            OcrEngineMode::OEM_TESSERACT_ONLY,
+            OcrEngineMode::OEM_LSTM_ONLY,
+            OcrEngineMode::OEM_TESSERACT_ONLY,
+        ] {
+            api.set_engine_mode(oem).unwrap();
+            assert_eq!(api.engine_mode(), Some(oem));
+            assert!(!api.has_recognized());
+            api.set_image_pix(&fixture("img.png"));
+            assert!(api.get_utf8_text().unwrap().contains("Rust"));
+        }
+    }
+}
+
+#[test]
+fn page_seg_mode_variable() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    assert_eq!(api.get_int_variable("tessedit_pageseg_mode").unwrap(), None);
+    api.init(None, "eng").unwrap();
+    for _ in 0..2 {
+        api.set_page_seg_mode(PageSegMode::PSM_SINGLE_WORD);
+        assert_eq!(api.page_seg_mode(), PageSegMode::PSM_SINGLE_WORD);
+        assert_eq!(
+            api.get_int_variable("tessedit_pageseg_mode").unwrap(),
+            Some(PageSegMode::PSM_SINGLE_WORD as i32)
+        );
+        api.reinit(None, "eng", OcrEngineMode::OEM_LSTM_ONLY)
+            .unwrap();
+    }
+    assert_eq!(api.get_int_variable("no_such_variable").unwrap(), None);
+    assert!(matches!(
+        api.get_int_variable("tessedit_pageseg\0mode"),
+        Err(TesseractError::NulError(_))
+    ));
+}
+
+#[test]
+fn create_and_drop_without_init() {
+    let api = TessBaseApi::new().unwrap();
+    assert!(!api.as_ptr().is_null());
+    assert!(!api.has_recognized());
+    drop(api);
+}
+
+#[test]
+fn set_image_rejects_short_buffer() {
+    let mut api = TessBaseApi::new().unwrap();
+    assert!(matches!(
+        api.set_image(&[0; 99], 10, 10, 1, 10),
+        Err(TesseractError::InvalidImage(_))
+    ));
+}
+
+#[test]
+fn set_image_rejects_degenerate_dimensions() {
+    let mut api = TessBaseApi::new().unwrap();
+    for &(width, height, bytes_per_pixel, bytes_per_line) in &[
+        (0, 10, 1, 10),
+        (10, 0, 1, 10),
+        // Lines shorter than a row of pixels.
+        (10, 10, 3, 20),
+        // width * bytes_per_pixel overflows i32.
+        (i32::MAX, 1, 4, i32::MAX),
+        // bytes_per_line * height is far larger than the buffer.
+        (i32::MAX, i32::MAX, 1, i32::MAX),
+    ] {
+        assert!(
+            matches!(
+                api.set_image(&[0; 100], width, height, bytes_per_pixel, bytes_per_line),
+                Err(TesseractError::InvalidImage(_))
+            ),
+            "{}x{}, {} bytes per pixel, {} per line",
+            width,
+            height,
+            bytes_per_pixel,
+            bytes_per_line
+        );
+    }
+}
+
+/// Text of the `ocrx_word` spans of an hOCR document.
+fn hocr_words(hocr: &str) -> Vec<String> {
+    hocr.split("class='ocrx_word'")
+        .skip(1)
+        .map(|span| {
+            let content = &span[span.find('>').unwrap() + 1..span.find("</span>").unwrap()];
+            let mut word = String::new();
+            let mut in_tag = false;
+            for c in content.chars() {
+                match c {
+                    '<' => in_tag = true,
+                    '>' => in_tag = false,
+                    c if !in_tag => word.push(c),
+                    _ => {}
+                }
+            }
+            word.replace("&amp;", "&")
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&#39;", "'")
+        })
+        .collect()
+}
+
+#[test]
+fn recognize_once_then_extract() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    api.recognize().unwrap();
+    let text = api.get_utf8_text().unwrap();
+    let hocr = api.get_hocr_text(0).unwrap();
+    let mut text_words = text.split_whitespace().collect::<Vec<_>>();
+    let mut hocr_words = hocr_words(&hocr);
+    text_words.sort_unstable();
+    hocr_words.sort_unstable();
+    assert!(!text_words.is_empty());
+    assert_eq!(text_words, hocr_words);
+}
+
+#[test]
+fn ocr_to_file() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("ocr_to_file");
+    fs::create_dir_all(&out_dir).unwrap();
+    let base = out_dir.join("img");
+    for format in [OutputFormat::Txt, OutputFormat::Pdf].iter().copied() {
+        let output = base.with_extension(format.extension());
+        let _ = fs::remove_file(&output);
+        api.ocr_to_file(Path::new("img.png"), base.to_str().unwrap(), format)
+            .unwrap();
+        assert!(fs::metadata(&output).unwrap().len() > 0);
+    }
+    assert!(fs::read_to_string(base.with_extension("txt"))
+        .unwrap()
+        .contains("Rust"));
+    assert!(matches!(
+        api.ocr_to_file(
+            Path::new("missing.png"),
+            base.to_str().unwrap(),
+            OutputFormat::Txt
+        ),
+        Err(TesseractError::ProcessPagesError)
+    ));
+}
+
+#[test]
+fn chained_renderers() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("chained_renderers");
+    fs::create_dir_all(&out_dir).unwrap();
+    let base = out_dir.join("img");
+    let base = base.to_str().unwrap();
+    let mut renderer = Renderer::text(base).unwrap();
+    renderer.insert(Renderer::hocr(base, false).unwrap());
+    api.process_pages(Path::new("img.png"), &renderer).unwrap();
+    drop(renderer);
+
+    let text = fs::read_to_string(out_dir.join("img.txt")).unwrap();
+    assert!(text.contains("Rust"));
+    let hocr = fs::read_to_string(out_dir.join("img.hocr")).unwrap();
+    assert!(hocr.contains("class='ocr_page'"));
+    assert!(hocr_words(&hocr).contains(&"Rust".to_string()));
+}
+
+/// The `/Title` of a PDF's document information, which tesseract writes as
+/// hex-encoded UTF-16BE.
+fn pdf_title(pdf: &[u8]) -> String {
+    let pdf = String::from_utf8_lossy(pdf);
+    let start = pdf.find("/Title <").expect("no /Title") + "/Title <".len();
+    let hex = &pdf[start..start + pdf[start..].find('>').unwrap()];
+    let units: Vec<u16> = (0..hex.len())
+        .step_by(4)
+        .map(|i| u16::from_str_radix(&hex[i..i + 4], 16).unwrap())
+        .collect();
+    assert_eq!(units.first(), Some(&0xFEFF), "no byte order mark");
+    String::from_utf16(&units[1..]).unwrap()
+}
+
+#[test]
+fn pdf_titled_with_output_name() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("pdf_title");
+    fs::create_dir_all(&out_dir).unwrap();
+    let base = out_dir.join("quarterly report");
+    api.ocr_to_file(
+        Path::new("img.png"),
+        base.to_str().unwrap(),
+        OutputFormat::Pdf,
+    )
+    .unwrap();
+    let pdf = fs::read(base.with_extension("pdf")).unwrap();
+    assert_eq!(pdf_title(&pdf), "quarterly report");
+
+    // A title set by the caller wins.
+    api.set_variable("document_title", "Q3").unwrap();
+    api.ocr_to_file(
+        Path::new("img.png"),
+        base.to_str().unwrap(),
+        OutputFormat::Pdf,
+    )
+    .unwrap();
+    assert_eq!(
+        pdf_title(&fs::read(base.with_extension("pdf")).unwrap()),
+        "Q3"
+    );
+    api.set_variable("document_title", "").unwrap();
+    let mut pdf = Vec::new();
+    api.render_to_writer(Path::new("img.png"), OutputFormat::Pdf, &mut pdf)
+        .unwrap();
+    assert_eq!(pdf_title(&pdf), "img");
+}
+
+#[test]
+fn render_hocr_to_writer() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let mut hocr = Vec::new();
+    api.render_to_writer(Path::new("img.png"), OutputFormat::Hocr, &mut hocr)
+        .unwrap();
+    let hocr = String::from_utf8(hocr).unwrap();
+    assert!(hocr.starts_with("<?xml"));
+    assert!(hocr.trim_end().ends_with("</html>"));
+    assert!(hocr.contains("class='ocr_page'"));
+    for tag in ["div", "p", "span"].iter() {
+        assert_eq!(
+            hocr.matches(&format!("<{} ", tag)).count(),
+            hocr.matches(&format!("</{}>", tag)).count(),
+            "unbalanced <{}>",
+            tag
+        );
+    }
+    assert!(hocr_words(&hocr).iter().any(|w| w == "Rust"));
+}
+
+#[test]
+fn has_recognized() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    assert!(!api.has_recognized());
+    api.set_image_pix(&fixture("img.png"));
+    assert!(!api.has_recognized());
+    api.recognize().unwrap();
+    assert!(api.has_recognized());
+    api.clear();
+    assert!(!api.has_recognized());
+}
+
+#[test]
+fn config_files() {
+    require_tessdata!("eng");
+    let mut api = TessConfig::new()
+        .config("digits")
+        .build(None, "eng")
+        .unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    let text = api.get_utf8_text().unwrap();
+    assert!(text
+        .chars()
+        .all(|c| c.is_ascii_digit() || "-.".contains(c) || c.is_whitespace()));
+}
+
+#[test]
+fn available_configs() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let system_tessdata = PathBuf::from(api.datapath().unwrap());
+
+    let datapath = Path::new(env!("CARGO_TARGET_TMPDIR")).join("configs_tessdata");
+    let configs = datapath.join("configs");
+    fs::create_dir_all(configs.join("nested")).unwrap();
+    fs::copy(
+        system_tessdata.join("eng.traineddata"),
+        datapath.join("eng.traineddata"),
+    )
+    .unwrap();
+    fs::write(configs.join("quiet"), "debug_file /dev/null\n").unwrap();
+    fs::write(
+        configs.join("digits"),
+        "tessedit_char_whitelist 0123456789\n",
+    )
+    .unwrap();
+
+    api.init(Some(datapath.to_str().unwrap()), "eng").unwrap();
+    assert_eq!(api.available_configs(), ["digits", "quiet"]);
+
+    assert!(TessBaseApi::new().unwrap().available_configs().is_empty());
+}
+
+#[test]
+fn init_and_loaded_languages() {
+    require_tessdata!("eng+osd");
+    let mut api = TessBaseApi::new().unwrap();
+    assert_eq!(api.init_languages(), None);
+    assert!(api.loaded_languages().is_empty());
+    api.init(None, "eng+osd").unwrap();
+    assert_eq!(api.init_languages().as_deref(), Some("eng+osd"));
+    let loaded = api.loaded_languages();
+    assert!(loaded.iter().any(|l| l == "eng"));
+    assert!(loaded.iter().any(|l| l == "osd"));
+    assert!(api.extra_loaded_languages().is_empty());
+}
+
+#[test]
+fn missing_language_data() {
+    require_tessdata!("eng");
+    let mut found = TessBaseApi::new().unwrap();
+    found.init(None, "eng").unwrap();
+    let datapath = found.datapath().unwrap();
+    match TessBaseApi::new()
+        .unwrap()
+        .init(Some(&datapath), "eng+notalanguage")
+    {
+        Err(TesseractError::MissingLanguageData { lang, .. }) => assert_eq!(lang, "notalanguage"),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn mutable_iterator() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    assert!(api.mutable_iterator().is_none());
+    api.recognize().unwrap();
+    let mut it = api.mutable_iterator().unwrap();
+    let mut words = Vec::new();
+    loop {
+        words.extend(it.get_utf8_text(PageIteratorLevel::RIL_WORD));
+        if !it.next(PageIteratorLevel::RIL_WORD) {
+            break;
+        }
+    }
+    drop(it);
+    assert_eq!(
+        words.join(" "),
+        api.get_utf8_text()
+            .unwrap()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    );
+}
+
+#[test]
+fn no_iterator_after_layout_analysis() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    assert!(!api.connected_components().is_empty());
+    assert!(api.get_iterator().is_none());
+    assert!(api.mutable_iterator().is_none());
+    drop(api.analyse_layout().unwrap());
+    assert!(api.get_iterator().is_none());
+
+    api.recognize().unwrap();
+    assert!(api.analyse_layout().is_some());
+    assert!(!api.has_recognized());
+    assert!(api.get_iterator().is_none());
+    api.get_utf8_text().unwrap();
+    assert!(api.get_iterator().is_some());
+}
+
+#[test]
+fn word_origin() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    api.recognize().unwrap();
+    let mut it = api.get_iterator().unwrap();
+    let mut dictionary_words = Vec::new();
+    loop {
+        let word = it.get_utf8_text(PageIteratorLevel::RIL_WORD).unwrap();
+        if it.word_is_from_dictionary() {
+            dictionary_words.push(word.clone());
+        }
+        // The fixture has no digits.
+        assert!(!it.word_is_numeric(), "{} is numeric", word);
+        assert_eq!(it.word_recognition_language().as_deref(), Some("eng"));
+        if !it.next(PageIteratorLevel::RIL_WORD) {
+            break;
+        }
+    }
+    assert!(dictionary_words.iter().any(|w| w == "companies"));
+}
+
+#[test]
+fn symbol_attributes() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_page_seg_mode(PageSegMode::PSM_SINGLE_LINE);
+    api.set_image_pix(&footnote_fixture());
+    api.recognize().unwrap();
+    let mut it = api.get_iterator().unwrap();
+    let mut superscripts = Vec::new();
+    loop {
+        if it.symbol_is_superscript() {
+            superscripts.extend(it.get_utf8_text(PageIteratorLevel::RIL_SYMBOL));
+        }
+        if !it.next(PageIteratorLevel::RIL_SYMBOL) {
+            break;
+        }
+    }
+    assert!(!superscripts.is_empty());
+}
+
+#[test]
+fn symbol_choices() {
+    require_tessdata!("eng");
+    // Shrunk until some letters could be others.
+    let word = first_element_fixture(PageIteratorLevel::RIL_WORD);
+    let word = unsafe { Pix::from_raw(pixScale(word.as_ptr(), 0.4, 0.4)) }.unwrap();
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_variable("lstm_choice_mode", "2").unwrap();
+    api.set_page_seg_mode(PageSegMode::PSM_SINGLE_WORD);
+    api.set_image_pix(&word);
+    api.recognize().unwrap();
+
+    let mut it = api.get_iterator().unwrap();
+    let mut symbols = 0;
+    loop {
+        let choices = it.symbol_choices(3);
+        assert!(!choices.is_empty() && choices.len() <= 3);
+        assert!(choices.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert!(choices.iter().all(|(text, _)| !text.is_empty()));
+        assert_eq!(it.symbol_choices(1), choices[..1].to_vec());
+        assert!(it.symbol_choices(0).is_empty());
+        symbols += 1;
+        if !it.next(PageIteratorLevel::RIL_SYMBOL) {
+            break;
+        }
+    }
+    assert!(symbols > 1);
+    assert!(it.symbol_choices(3).is_empty());
+}
+
+#[test]
+fn opencl_device() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    // Either answer is fine; this only checks the call is safe either way.
+    if let Some(device) = api.opencl_device() {
+        assert_ne!(device, 0);
+    }
+}
@@ -0,0 +1,45 @@
+// Runs the unit tests of the build script helpers under build/.
+
+#[allow(dead_code)]
+#[path = "../build/arch.rs"]
+mod arch;
+
+#[allow(dead_code)]
+#[path = "../build/bundled.rs"]
+mod bundled;
+
+#[allow(dead_code)]
+#[path = "../build/manifest.rs"]
+mod manifest;
+
+#[allow(dead_code)]
+#[path = "../build/probe.rs"]
+mod probe;
+
+#[allow(dead_code)]
+#[path = "../build/paths.rs"]
+mod paths;
+
+#[allow(dead_code)]
+#[path = "../build/public_types.rs"]
+mod public_types;
+
+#[allow(dead_code)]
+#[path = "../build/sanitizer.rs"]
+mod sanitizer;
+
+#[allow(dead_code)]
+#[path = "../build/source.rs"]
+mod source;
+
+#[allow(dead_code)]
+#[path = "../build/symbols.rs"]
+mod symbols;
+
+#[allow(dead_code)]
+#[path = "../build/version.rs"]
+mod version;
+
+#[allow(dead_code)]
+#[path = "../build/link.rs"]
+mod link;
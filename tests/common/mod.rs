@@ -0,0 +1,101 @@
+// Shared helpers for the integration tests.
+#![allow(dead_code)]
+
+use leptonica_sys::{
+    pixConvertTo8, pixCreate, pixRasterop, pixRead, pixSetBlackOrWhite, L_SET_WHITE, PIX_CLR,
+    PIX_SRC,
+};
+use std::ffi::CString;
+use std::ptr;
+use tesseract_sys::api::{Pix, Rect, TessBaseApi};
+
+/// Whether tesseract can load `language` without an explicit datapath, i.e.
+/// through `TESSDATA_PREFIX` or its compiled-in default. If it can't, prints
+/// a skip message, or panics when the `require-tessdata` feature is enabled.
+pub fn has_tessdata(language: &str) -> bool {
+    if TessBaseApi::new().unwrap().init(None, language).is_ok() {
+        return true;
+    }
+    let message = format!(
+        "no tessdata for `{}` (TESSDATA_PREFIX={:?})",
+        language,
+        std::env::var_os("TESSDATA_PREFIX")
+    );
+    if cfg!(feature = "require-tessdata") {
+        panic!("{}", message);
+    }
+    eprintln!("skipping: {}", message);
+    false
+}
+
+/// Returns early from the calling test when `has_tessdata` is false.
+#[macro_export]
+macro_rules! require_tessdata {
+    ($language:expr) => {
+        if !$crate::common::has_tessdata($language) {
+            return;
+        }
+    };
+}
+
+pub fn fixture(path: &str) -> Pix {
+    let path = CString::new(path).unwrap();
+    unsafe { Pix::from_raw(pixRead(path.as_ptr())) }.expect("failed to read fixture")
+}
+
+/// A fixture converted to 8 bit grey.
+pub fn grey_fixture(path: &str) -> Pix {
+    let image = fixture(path);
+    unsafe { Pix::from_raw(pixConvertTo8(image.as_ptr(), 0)) }.unwrap()
+}
+
+/// A white 8 bit page to `paste` fixtures onto.
+pub fn page(width: i32, height: i32) -> Pix {
+    unsafe {
+        let page = Pix::from_raw(pixCreate(width, height, 8)).unwrap();
+        pixSetBlackOrWhite(page.as_ptr(), L_SET_WHITE as i32);
+        page
+    }
+}
+
+/// Copies all of `source` onto `page` with its top left corner at `left`,
+/// `top`. Whatever falls outside `page` is cut off.
+pub fn paste(page: &Pix, source: &Pix, left: i32, top: i32) {
+    unsafe {
+        pixRasterop(
+            page.as_ptr(),
+            left,
+            top,
+            source.width(),
+            source.height(),
+            PIX_SRC as i32,
+            source.as_ptr(),
+            0,
+            0,
+        );
+    }
+}
+
+/// Paints `rect` of `page` black.
+pub fn fill_black(page: &Pix, rect: Rect) {
+    unsafe {
+        pixRasterop(
+            page.as_ptr(),
+            rect.left,
+            rect.top,
+            rect.width,
+            rect.height,
+            PIX_CLR as i32,
+            ptr::null_mut(),
+            0,
+            0,
+        );
+    }
+}
+
+/// Copies `rect` of `source` into a new 8 bit image.
+pub fn crop(source: &Pix, rect: Rect) -> Pix {
+    let cropped = page(rect.width, rect.height);
+    paste(&cropped, source, -rect.left, -rect.top);
+    cropped
+}
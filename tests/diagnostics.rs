@@ -0,0 +1,39 @@
+#![cfg(feature = "api")]
+
+// Kept in its own test binary because debug_file is global: it would swallow
+// the diagnostics of every other test in the process.
+
+mod common;
+
+use std::fs;
+use std::path::Path;
+use tesseract_sys::api::{PixelFormat, TessBaseApi};
+
+// Raw pixels carry no resolution, so tesseract reports its estimate.
+fn recognise_blank_page(api: &mut TessBaseApi) {
+    let (width, height) = (200, 100);
+    let white = vec![255; (width * height) as usize];
+    api.set_image_fmt(&white, width, height, PixelFormat::Grayscale)
+        .unwrap();
+    api.get_utf8_text().unwrap();
+}
+
+#[test]
+fn diagnostics_to_file() {
+    require_tessdata!("eng");
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("tesseract-diagnostics.log");
+    let _ = fs::remove_file(&path);
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_debug_file(&path).unwrap();
+    recognise_blank_page(&mut api);
+    // The file is closed, and so flushed, at the next message.
+    api.set_variable("debug_file", "").unwrap();
+    recognise_blank_page(&mut api);
+    let log = fs::read_to_string(&path).unwrap();
+    assert!(log.contains("Estimating resolution"), "{:?}", log);
+
+    api.silence_diagnostics().unwrap();
+    recognise_blank_page(&mut api);
+    api.set_variable("debug_file", "").unwrap();
+}
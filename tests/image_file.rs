@@ -0,0 +1,84 @@
+#![cfg(feature = "image-file")]
+
+mod common;
+
+use common::fixture;
+use leptonica_sys::{pixCreate, pixGetWpl};
+use std::fs;
+use std::path::Path;
+use tesseract_sys::api::{pix_bytes_per_line, ImageFormat, Pix, TessBaseApi, TesseractError};
+
+#[test]
+fn write_png_as_tiff() {
+    let image = fixture("img.png");
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("img.tif");
+    let _ = fs::remove_file(&path);
+    image.write_to_path(&path, ImageFormat::Tiff).unwrap();
+
+    let header = fs::read(&path).unwrap();
+    assert!(header.starts_with(b"II*\0") || header.starts_with(b"MM\0*"));
+    let reloaded = fixture(path.to_str().unwrap());
+    assert_eq!(
+        (reloaded.width(), reloaded.height()),
+        (image.width(), image.height())
+    );
+}
+
+#[test]
+fn write_to_mem() {
+    let png = fixture("img.png").write_to_mem(ImageFormat::Png).unwrap();
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+}
+
+#[test]
+fn ocr_png_and_jpeg_bytes() {
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    let png = fs::read("img.png").unwrap();
+    let jpeg = fixture("img.png").write_to_mem(ImageFormat::Jpeg).unwrap();
+    assert!(jpeg.starts_with(b"\xff\xd8"));
+    for bytes in [png, jpeg].iter() {
+        assert_eq!(
+            api.ocr_image_bytes(bytes).unwrap(),
+            include_str!("../img.txt")
+        );
+    }
+}
+
+#[test]
+fn garbage_bytes_fail_to_decode() {
+    let garbage = b"not an image";
+    assert!(matches!(
+        Pix::read_mem(garbage),
+        Err(TesseractError::DecodeError)
+    ));
+    assert!(matches!(
+        TessBaseApi::new().unwrap().ocr_image_bytes(garbage),
+        Err(TesseractError::DecodeError)
+    ));
+    assert!(matches!(
+        Pix::read_mem(&[]),
+        Err(TesseractError::DecodeError)
+    ));
+}
+
+#[test]
+fn bytes_per_line_matches_leptonica() {
+    assert_eq!(pix_bytes_per_line(100, 1), 16);
+    assert_eq!(pix_bytes_per_line(101, 8), 104);
+    assert_eq!(pix_bytes_per_line(0, 8), 0);
+    for depth in [1, 2, 4, 8, 16, 24, 32] {
+        for width in [1, 7, 31, 32, 33, 100, 101, 640] {
+            let pix = unsafe { Pix::from_raw(pixCreate(width, 1, depth)) }.unwrap();
+            let wpl = unsafe { pixGetWpl(pix.as_ptr()) };
+            assert_eq!(
+                pix_bytes_per_line(width as u32, depth as u32),
+                4 * wpl as usize,
+                "width {}, depth {}",
+                width,
+                depth
+            );
+        }
+    }
+}
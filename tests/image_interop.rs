@@ -0,0 +1,77 @@
+#![cfg(feature = "image-interop")]
+
+mod common;
+
+use common::grey_fixture;
+use image::{DynamicImage, GenericImageView, GrayImage};
+use leptonica_sys::pixGetPixel;
+use tesseract_sys::api::{crop_region, PixelFormat, Rect, TessBaseApi};
+use tesseract_sys::PageIteratorLevel;
+
+/// A fixture as an 8 bit grey `DynamicImage`.
+fn fixture_image(path: &str) -> DynamicImage {
+    let grey = grey_fixture(path);
+    let mut bytes = Vec::with_capacity((grey.width() * grey.height()) as usize);
+    for y in 0..grey.height() {
+        for x in 0..grey.width() {
+            let mut value = 0;
+            unsafe { pixGetPixel(grey.as_ptr(), x, y, &mut value) };
+            bytes.push(value as u8);
+        }
+    }
+    let grey = GrayImage::from_raw(grey.width() as u32, grey.height() as u32, bytes).unwrap();
+    DynamicImage::ImageLuma8(grey)
+}
+
+#[test]
+fn crop_word_region() {
+    require_tessdata!("eng");
+    let image = fixture_image("img.png");
+    let (width, height) = image.dimensions();
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_fmt(
+        image.to_luma8().as_raw(),
+        width as i32,
+        height as i32,
+        PixelFormat::Grayscale,
+    )
+    .unwrap();
+    let bbox = api
+        .analyse_layout()
+        .unwrap()
+        .bounding_box(PageIteratorLevel::RIL_WORD)
+        .unwrap();
+
+    let word = crop_region(&image, bbox);
+    assert_eq!(word.dimensions(), (bbox.width as u32, bbox.height as u32));
+    let (page, word) = (image.to_luma8(), word.to_luma8());
+    for y in 0..bbox.height as u32 {
+        for x in 0..bbox.width as u32 {
+            assert_eq!(
+                word.get_pixel(x, y),
+                page.get_pixel(bbox.left as u32 + x, bbox.top as u32 + y)
+            );
+        }
+    }
+    assert!(word.pixels().any(|pixel| pixel.0[0] < 128));
+
+    // Cut off at the bottom right corner.
+    let past = Rect {
+        left: width as i32 - 10,
+        top: height as i32 - 5,
+        width: 100,
+        height: 100,
+    };
+    assert_eq!(crop_region(&image, past).dimensions(), (10, 5));
+}
+
+#[test]
+fn crop_region_past_i32_max() {
+    let rect = Rect::from((u32::MAX, 0, 1, 1));
+    let region = <(u32, u32, u32, u32)>::from(rect);
+    assert_eq!(region, (i32::MAX as u32, 0, 0, 1));
+    assert_eq!(Rect::from(region), rect);
+    let image = DynamicImage::new_luma8(10, 10);
+    assert_eq!(crop_region(&image, rect).dimensions(), (0, 1));
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "api")]
+
+// Kept in its own test binary because it changes TESSDATA_PREFIX for the
+// whole process.
+
+mod common;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use tesseract_sys::api::{TessBaseApi, TesseractError};
+
+#[test]
+fn init_default_uses_tessdata_prefix() {
+    require_tessdata!("eng");
+    let mut found = TessBaseApi::new().unwrap();
+    found.init(None, "eng").unwrap();
+    let system_tessdata = found.datapath().unwrap();
+
+    let fixture = Path::new(env!("CARGO_TARGET_TMPDIR")).join("tessdata");
+    fs::create_dir_all(&fixture).unwrap();
+    fs::copy(
+        Path::new(&system_tessdata).join("eng.traineddata"),
+        fixture.join("eng.traineddata"),
+    )
+    .unwrap();
+
+    env::set_var("TESSDATA_PREFIX", fixture.join("missing"));
+    match TessBaseApi::new().unwrap().init_default("eng") {
+        Err(TesseractError::TessdataNotFound {
+            tessdata_prefix: Some(prefix),
+        }) => assert_eq!(prefix, fixture.join("missing")),
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    env::set_var("TESSDATA_PREFIX", &fixture);
+    let mut api = TessBaseApi::new().unwrap();
+    api.init_default("eng").unwrap();
+    assert_eq!(
+        Path::new(&api.datapath().unwrap()).canonicalize().unwrap(),
+        fixture.canonicalize().unwrap()
+    );
+}
@@ -0,0 +1,22 @@
+#![cfg(feature = "api")]
+
+// Kept in its own test binary because it sets OMP_THREAD_LIMIT for the whole
+// process, before anything recognises.
+
+mod common;
+
+use common::fixture;
+use std::env;
+use tesseract_sys::api::{set_thread_limit, TessBaseApi};
+
+#[test]
+fn recognise_with_one_thread() {
+    set_thread_limit(1);
+    assert_eq!(env::var("OMP_THREAD_LIMIT").as_deref(), Ok("1"));
+
+    require_tessdata!("eng");
+    let mut api = TessBaseApi::new().unwrap();
+    api.init(None, "eng").unwrap();
+    api.set_image_pix(&fixture("img.png"));
+    assert_eq!(api.get_utf8_text().unwrap(), include_str!("../img.txt"));
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "api")]
+
+mod common;
+
+use leptonica_sys::{boxaDestroy, boxaGetCount, pixDestroy, pixRead};
+use std::ptr;
+use tesseract_sys::util::{delete_int_array, delete_text, delete_text_array};
+use tesseract_sys::{
+    TessBaseAPICreate, TessBaseAPIDelete, TessBaseAPIGetLoadedLanguagesAsVector,
+    TessBaseAPIGetTextlines, TessBaseAPIInit3, TessBaseAPISetImage2,
+};
+
+// Meant to be run under a leak checker (valgrind, or
+// RUSTFLAGS=-Zsanitizer=leak on nightly) as well as plainly.
+#[test]
+fn layout_query_then_delete() {
+    require_tessdata!("eng");
+    unsafe {
+        let api = TessBaseAPICreate();
+        TessBaseAPIInit3(api, ptr::null(), b"eng\0".as_ptr().cast());
+        let mut image = pixRead(b"img.png\0".as_ptr().cast());
+        TessBaseAPISetImage2(api, image);
+
+        let mut blockids = ptr::null_mut();
+        let mut boxa = TessBaseAPIGetTextlines(api, ptr::null_mut(), &mut blockids);
+        assert!(boxaGetCount(boxa) > 0);
+        assert!(!blockids.is_null());
+        delete_int_array(blockids);
+        boxaDestroy(&mut boxa);
+
+        let languages = TessBaseAPIGetLoadedLanguagesAsVector(api);
+        assert!(!languages.is_null());
+        delete_text_array(languages);
+
+        delete_text(ptr::null_mut());
+        pixDestroy(&mut image);
+        TessBaseAPIDelete(api);
+    }
+}